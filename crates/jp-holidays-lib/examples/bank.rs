@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{bank::BankCalendar, business::BusinessCalendar, client::Client};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+    let calendar = BankCalendar::new(&client);
+
+    // 2026年1月2日は金曜日ですが、銀行は休業します。
+    let date = NaiveDate::from_ymd_opt(2026, 1, 2).ok_or("存在しない日付です".to_string())?;
+
+    println!(
+        "2026年 1月 2日 は銀行の{}",
+        if calendar.is_business_day(date) {
+            "営業日です"
+        } else {
+            "休業日です"
+        }
+    );
+    println!(
+        "2026年 1月 2日 の翌銀行営業日は {}",
+        calendar.next_business_day(date)?
+    );
+
+    Ok(())
+}
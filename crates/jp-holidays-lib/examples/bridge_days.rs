@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::client::Client;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let start = NaiveDate::from_ymd_opt(2026, 1, 1).ok_or("存在しない日付です".to_string())?;
+    let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+
+    // 2日以下の営業日が休日に挟まれている期間
+    for bridge in client.bridge_days(start..=end, 2)? {
+        let days: Vec<String> = bridge.days.iter().map(|date| date.to_string()).collect();
+        let holidays: Vec<&str> = bridge
+            .before
+            .holidays
+            .iter()
+            .chain(bridge.after.holidays.iter())
+            .map(|holiday| holiday.name.as_str())
+            .collect();
+
+        println!(
+            "{} | 休みにすると {} 〜 {} の {} 連休 ({})",
+            days.join(", "),
+            bridge.before.start,
+            bridge.after.end,
+            bridge.combined_len(),
+            holidays.join("・")
+        );
+    }
+
+    Ok(())
+}
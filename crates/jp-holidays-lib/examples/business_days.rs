@@ -0,0 +1,22 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::client::Client;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let date = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("存在しない日付です".to_string())?;
+
+    // 翌営業日・前営業日を取得
+    println!("翌営業日: {}", client.next_business_day(date)?);
+    println!("前営業日: {}", client.previous_business_day(date)?);
+
+    // 10営業日後を取得
+    println!("10営業日後: {}", client.add_business_days(date, 10)?);
+
+    // 2026年 5月 の営業日数を取得
+    let end = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    println!("営業日数: {}", client.business_days_between(date, end)?);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use jp_holidays_lib::{client::Client, convention::BusinessDayConvention};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    for month in 1..=12 {
+        let fifth = client
+            .nth_business_day_of_month(2026, month, 5)?
+            .ok_or("営業日が足りません".to_string())?;
+        let last = client
+            .last_business_day_of_month(2026, month)?
+            .ok_or("営業日がありません".to_string())?;
+        let payday =
+            client.adjusted_day_of_month(2026, month, 25, BusinessDayConvention::Preceding)?;
+
+        println!(
+            "2026年 {:>2}月 | 営業日 {} 日 | 第5営業日 {} | 最終営業日 {} | 給与支払日 {}",
+            month,
+            client.count_business_days_in_month(2026, month)?,
+            fifth,
+            last,
+            payday
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use chrono::{Duration, NaiveTime, TimeZone, Utc};
+use jp_holidays_lib::{client::Client, hours::BusinessHours};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let lunch_start = NaiveTime::from_hms_opt(12, 0, 0).ok_or("存在しない時刻です".to_string())?;
+    let lunch_end = NaiveTime::from_hms_opt(13, 0, 0).ok_or("存在しない時刻です".to_string())?;
+    let hours = BusinessHours::new(&client).with_lunch_break(lunch_start, lunch_end);
+
+    // 2026年4月30日 16:00 (日本時間) に受け付けた問い合わせ
+    let received = Utc
+        .with_ymd_and_hms(2026, 4, 30, 7, 0, 0)
+        .single()
+        .ok_or("存在しない時刻です".to_string())?;
+
+    println!("受付時刻は営業時間内: {}", hours.is_open(&received));
+
+    let due = hours.add(&received, Duration::hours(8))?;
+    println!("8営業時間後の期限は {}", due);
+    println!(
+        "受付から期限までの営業時間は {} 時間",
+        hours.between(&received, &due)?.num_hours()
+    );
+
+    Ok(())
+}
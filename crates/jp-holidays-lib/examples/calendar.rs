@@ -0,0 +1,28 @@
+use chrono::{NaiveDate, Weekday};
+use jp_holidays_lib::{
+    business::BusinessCalendar,
+    calendar::{Calendar, WorkWeek},
+    client::Client,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 水曜日と日曜日が休みの店舗のカレンダー
+    let calendar =
+        Calendar::new(&client).with_work_week(WorkWeek::new([Weekday::Wed, Weekday::Sun]));
+
+    let date = NaiveDate::from_ymd_opt(2026, 7, 21).ok_or("存在しない日付です".to_string())?;
+
+    println!(
+        "2026年 7月 21日 の翌営業日は {}",
+        calendar.next_business_day(date)?
+    );
+    println!(
+        "2026年 7月 21日 の5営業日後は {}",
+        calendar.add_business_days(date, 5)?
+    );
+
+    Ok(())
+}
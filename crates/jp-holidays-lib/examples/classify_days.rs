@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{
+    business::BusinessCalendar, client::Client, days::DayKind, overlay::OverlayCalendar,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let start = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("存在しない日付です".to_string())?;
+    let end = NaiveDate::from_ymd_opt(2026, 5, 10).ok_or("存在しない日付です".to_string())?;
+
+    // すべての日付と種別
+    for day in client.classify_days(start..=end)? {
+        println!("{} | {:?}", day.date, day.kind);
+    }
+
+    // 営業日のみ
+    let business_days: Vec<NaiveDate> = client
+        .classify_days(start..=end)?
+        .business_days()
+        .map(|day| day.date)
+        .collect();
+    println!("営業日: {:?}", business_days);
+
+    // 会社独自の休業日を重ねたカレンダーでの休業日
+    let closure_start =
+        NaiveDate::from_ymd_opt(2026, 8, 12).ok_or("存在しない日付です".to_string())?;
+    let closure_end =
+        NaiveDate::from_ymd_opt(2026, 8, 14).ok_or("存在しない日付です".to_string())?;
+    let calendar = OverlayCalendar::new(&client)
+        .with_closures_between(closure_start..=closure_end, "お盆休み");
+
+    let month_start =
+        NaiveDate::from_ymd_opt(2026, 8, 1).ok_or("存在しない日付です".to_string())?;
+    let month_end = NaiveDate::from_ymd_opt(2026, 8, 31).ok_or("存在しない日付です".to_string())?;
+    let closures = calendar
+        .classify_days(month_start..=month_end)?
+        .of_kind(DayKind::Closure)
+        .count();
+    println!("8月の会社独自の休業日は {} 日です", closures);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{client::Client, clock::FixedClock};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // システムの時計 (日本時間)
+    println!("今日は {}", client.today());
+    if let Some(holiday) = client.next_holiday_from_today() {
+        println!("次の祝日は {} の{}です", holiday.date, holiday.name);
+    }
+
+    // 時計を固定
+    let date = NaiveDate::from_ymd_opt(2026, 5, 7).ok_or("存在しない日付です".to_string())?;
+    let client = client.with_clock(FixedClock::at_japan_date(date));
+
+    println!("固定した時計の現在時刻は {}", client.clock().now());
+    println!(
+        "{} は{}",
+        client.today(),
+        if client.is_today_day_off() {
+            "休日です"
+        } else {
+            "休日ではありません"
+        }
+    );
+    if let Some(days) = client.days_until_next_holiday() {
+        println!("次の祝日まであと {} 日です", days);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{business::BusinessCalendar, client::Client, config::load_calendar};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 設定ファイルから会社カレンダーを読み込みます。
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/config/calendar.toml");
+    let calendar = load_calendar(&client, path)?;
+
+    let date = NaiveDate::from_ymd_opt(2026, 8, 12).ok_or("存在しない日付です".to_string())?;
+    println!(
+        "2026年 8月 12日 の翌営業日は {}",
+        calendar.next_business_day(date)?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{client::Client, runs::NamedBreak};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let start = NaiveDate::from_ymd_opt(2026, 1, 1).ok_or("存在しない日付です".to_string())?;
+    let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+
+    // 3日以上の連休
+    for run in client
+        .day_off_runs(start..=end)?
+        .iter()
+        .filter(|run| run.days() >= 3)
+    {
+        let name = match run.name() {
+            Some(NamedBreak::GoldenWeek) => " (ゴールデンウィーク)",
+            Some(NamedBreak::SilverWeek) => " (シルバーウィーク)",
+            None => "",
+        };
+        println!("{} 〜 {} | {} 連休{}", run.start, run.end, run.days(), name);
+    }
+
+    Ok(())
+}
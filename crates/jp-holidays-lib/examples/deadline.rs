@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{
+    administrative::AdministrativeCalendar,
+    client::Client,
+    deadline::{Deadline, Period},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+    let calendar = AdministrativeCalendar::new(&client);
+
+    // 処分があったことを知った日の翌日から起算して3か月以内
+    let date = NaiveDate::from_ymd_opt(2026, 9, 28).ok_or("存在しない日付です".to_string())?;
+    let deadline = Deadline::new(Period::Months(3));
+
+    println!("期間の末日は {}", deadline.expiry(date)?);
+    println!(
+        "休日による延長後の期限は {}",
+        deadline.compute(&calendar, date)?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{
+    client::Client,
+    era::{Era, EraDate},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 改元の前後
+    for (year, month, day) in [(1989, 1, 7), (1989, 1, 8), (2019, 4, 30), (2019, 5, 1)] {
+        let date =
+            NaiveDate::from_ymd_opt(year, month, day).ok_or("存在しない日付です".to_string())?;
+        println!("{} | {}", date, EraDate::from_date(date)?.format());
+    }
+
+    // 和暦から西暦へ
+    let era_date = EraDate::new(Era::Reiwa, 8, 5, 3)?;
+    println!("{} は {}", era_date.format(), era_date.to_date());
+
+    // 和暦・曜日・祝日名
+    for day in 3..=7 {
+        let date = NaiveDate::from_ymd_opt(2026, 5, day).ok_or("存在しない日付です".to_string())?;
+        println!("{}", client.format_japanese(date)?);
+    }
+
+    Ok(())
+}
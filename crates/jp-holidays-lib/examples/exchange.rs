@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{
+    client::Client,
+    exchange::{ExchangeCalendar, Session},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+    let calendar = ExchangeCalendar::new(&client);
+
+    let date = NaiveDate::from_ymd_opt(2025, 12, 30).ok_or("存在しない日付です".to_string())?;
+
+    match calendar.session(date) {
+        Session::Closed => println!("2025年 12月 30日 は休場日です"),
+        Session::HalfDay => println!("2025年 12月 30日 は半日立会です"),
+        Session::FullDay => println!("2025年 12月 30日 は立会日です"),
+    }
+
+    println!(
+        "2025年 12月 30日 約定の受渡日は {}",
+        calendar.settlement_date(date)?
+    );
+
+    Ok(())
+}
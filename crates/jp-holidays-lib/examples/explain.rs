@@ -0,0 +1,40 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{
+    bank::BankCalendar, business::BusinessCalendar, client::Client, overlay::OverlayCalendar,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let start = NaiveDate::from_ymd_opt(2026, 8, 12).ok_or("存在しない日付です".to_string())?;
+    let end = NaiveDate::from_ymd_opt(2026, 8, 14).ok_or("存在しない日付です".to_string())?;
+    let inventory = NaiveDate::from_ymd_opt(2026, 1, 3).ok_or("存在しない日付です".to_string())?;
+
+    let calendar = OverlayCalendar::new(BankCalendar::new(&client))
+        .with_closures_between(start..=end, "お盆休み")
+        .with_working_day(inventory, "棚卸し");
+
+    for (month, day) in [(1, 3), (5, 3), (5, 6), (8, 11), (8, 12), (9, 22)] {
+        let date =
+            NaiveDate::from_ymd_opt(2026, month, day).ok_or("存在しない日付です".to_string())?;
+        println!(
+            "{} は{}",
+            date,
+            if calendar.is_day_off(date) {
+                "休日です"
+            } else {
+                "営業日です"
+            }
+        );
+        for reason in calendar.explain(date) {
+            println!(
+                "  - {} ({})",
+                reason.description(),
+                reason.source().description()
+            );
+        }
+    }
+
+    Ok(())
+}
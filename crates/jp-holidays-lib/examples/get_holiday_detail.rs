@@ -0,0 +1,20 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::client::Client;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 種別付きの祝日を取得
+    let date = NaiveDate::from_ymd_opt(2024, 2, 12).ok_or("存在しない日付です".to_string())?;
+
+    match client.get_holiday_detail(date) {
+        Some(holiday) => println!(
+            "2024年 2月 12日 は{} ({:?}, 振替元: {:?})",
+            holiday.name, holiday.kind, holiday.substitute_for
+        ),
+        None => println!("2024年 2月 12日 は祝日ではありません"),
+    };
+
+    Ok(())
+}
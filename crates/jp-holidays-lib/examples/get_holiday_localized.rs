@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{client::Client, locale::Locale};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 祝日名を各言語で取得
+    let date = NaiveDate::from_ymd_opt(2025, 7, 21).ok_or("存在しない日付です".to_string())?;
+
+    for locale in [
+        Locale::Japanese,
+        Locale::English,
+        Locale::Hiragana,
+        Locale::Romaji,
+    ] {
+        match client.get_holiday_localized(date, locale) {
+            Some(holiday) => println!("{:?}: {}", locale, holiday),
+            None => println!("2025年 7月 21日 は祝日ではありません"),
+        };
+    }
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use jp_holidays_lib::{client::Client, gotobi::GotobiCalendar};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+    let gotobi = GotobiCalendar::bank(&client);
+
+    for date in gotobi.list_gotobi(2026, 5)? {
+        if date.is_adjusted() {
+            println!(
+                "{} は休日のため {} に移動します",
+                date.nominal, date.adjusted
+            );
+        } else {
+            println!("{}", date.nominal);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::client::Client;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    for holiday in client.holidays_in_month(2026, 5)? {
+        println!("{} | {}", holiday.date, holiday.name);
+    }
+
+    let date = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    if let Some(holiday) = client.next_holiday(date) {
+        println!(
+            "2026年 6月 1日 の次の祝日は {} の{}です",
+            holiday.date, holiday.name
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use chrono::{TimeZone, Utc};
+use jp_holidays_lib::{client::Client, jst};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // UTC で動くサーバーで受け取った時刻
+    let instant = Utc
+        .with_ymd_and_hms(2026, 5, 3, 15, 30, 0)
+        .single()
+        .ok_or("存在しない時刻です".to_string())?;
+
+    println!(
+        "{} (UTC) は日本時間の {}",
+        instant,
+        jst::to_japan_datetime(&instant)
+    );
+    match client.get_holiday_at(&instant) {
+        Some(holiday) => println!("日本時間では{}です", holiday),
+        None => println!("日本時間では祝日ではありません"),
+    };
+
+    println!("日本時間の今日は {}", client.today());
+    println!(
+        "今日は{}",
+        if client.is_today_day_off() {
+            "休日です"
+        } else {
+            "休日ではありません"
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{client::Client, leave::LeavePlanner, overlay::OverlayCalendar};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // お盆休みのある会社で、有給休暇を2日使う場合
+    let start = NaiveDate::from_ymd_opt(2026, 8, 12).ok_or("存在しない日付です".to_string())?;
+    let end = NaiveDate::from_ymd_opt(2026, 8, 14).ok_or("存在しない日付です".to_string())?;
+    let calendar = OverlayCalendar::new(&client).with_closures_between(start..=end, "お盆休み");
+
+    for suggestion in LeavePlanner::new(&calendar, 2)
+        .with_limit(5)
+        .suggest(2026)?
+    {
+        let leave_days: Vec<String> = suggestion
+            .leave_days
+            .iter()
+            .map(|date| date.to_string())
+            .collect();
+
+        println!(
+            "{} 〜 {} | {} 連休 | 休暇: {}",
+            suggestion.start,
+            suggestion.end,
+            suggestion.days(),
+            leave_days.join(", ")
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use jp_holidays_lib::{client::Client, holiday::HolidayId};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 「体育の日」と「スポーツの日」をまとめて取得します。
+    for holiday in client.list_holidays_by_id(HolidayId::SportsDay) {
+        println!("{} | {}", holiday.date, holiday.name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use jp_holidays_lib::client::Client;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 振替休日と振り替えられた元の祝日を表示します。
+    for (substitute, _) in client.list_substitute_holidays() {
+        if let Some(name) = client.get_holiday_display_name(substitute.date) {
+            println!("{} | {}", substitute.date, name);
+        }
+    }
+
+    Ok(())
+}
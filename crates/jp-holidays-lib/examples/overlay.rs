@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{business::BusinessCalendar, client::Client, overlay::OverlayCalendar};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    let year_end = NaiveDate::from_ymd_opt(2026, 12, 29).ok_or("存在しない日付です".to_string())?;
+    let new_year = NaiveDate::from_ymd_opt(2027, 1, 3).ok_or("存在しない日付です".to_string())?;
+    let founding_day =
+        NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+
+    // 年末年始と創立記念日を休業日として追加します。
+    let calendar = OverlayCalendar::new(&client)
+        .with_closures_between(year_end..=new_year, "年末年始")
+        .with_closure(founding_day, "創立記念日");
+
+    let date = NaiveDate::from_ymd_opt(2026, 12, 28).ok_or("存在しない日付です".to_string())?;
+    println!(
+        "2026年 12月 28日 の翌営業日は {}",
+        calendar.next_business_day(date)?
+    );
+
+    Ok(())
+}
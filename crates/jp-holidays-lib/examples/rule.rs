@@ -0,0 +1,29 @@
+use chrono::Weekday;
+use jp_holidays_lib::{
+    business::BusinessCalendar, client::Client, holiday::HolidayId, overlay::OverlayCalendar,
+    rule::Rule,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 毎年繰り返す休業日のルールを、祝日データに重ねます。
+    let calendar = OverlayCalendar::new(&client)
+        .with_rule(&Rule::span(12, 29, 1, 3)?.with_label("年末年始"))?
+        .with_rule(&Rule::nth_weekday(11, 3, Weekday::Fri)?.with_label("社内運動会"))?
+        .with_rule(
+            &Rule::holiday(HolidayId::ComingOfAgeDay)
+                .next_business_day()
+                .with_label("成人の日の翌営業日"),
+        )?;
+
+    for (date, entry) in calendar.list_overrides().iter().rev().take(10) {
+        println!("{} | {}", date, entry.label());
+    }
+
+    let coverage = calendar.coverage();
+    println!("{} 〜 {}", coverage.start(), coverage.end());
+
+    Ok(())
+}
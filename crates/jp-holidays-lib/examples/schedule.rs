@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use jp_holidays_lib::{
+    client::Client,
+    convention::BusinessDayConvention,
+    schedule::{Frequency, Schedule},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::init().await?;
+
+    // 毎月末払い、休日の場合は翌営業日 (月をまたぐ場合は前営業日)
+    let start = NaiveDate::from_ymd_opt(2025, 12, 31).ok_or("存在しない日付です".to_string())?;
+    let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+
+    let schedule = Schedule::new(start, end, Frequency::Monthly)
+        .with_convention(BusinessDayConvention::ModifiedFollowing);
+
+    for date in schedule.generate(&client)? {
+        println!("{} | {}", date.unadjusted, date.adjusted);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 行政機関の休日に関する法律に基づく行政機関の休日のカレンダーです。
+///
+/// 以下の日を休日とします。
+///
+/// - 日曜日と土曜日
+/// - 国民の祝日に関する法律に規定する休日 (振替休日と国民の休日を含みます)
+/// - 12月29日から翌年1月3日まで
+///
+/// 営業日 (開庁日) の計算は `crate::business::BusinessCalendar` のメソッドで行います。
+/// 期限の計算は `crate::deadline::Deadline` と組み合わせて使用します。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use jp_holidays_lib::{
+///     administrative::AdministrativeCalendar, business::BusinessCalendar, client::Client,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///     let calendar = AdministrativeCalendar::new(&client);
+///
+///     let date = NaiveDate::from_ymd_opt(2025, 12, 26).ok_or("存在しない日付です".to_string())?;
+///     println!("2025年 12月 26日 の翌開庁日は {}", calendar.next_business_day(date)?);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct AdministrativeCalendar<'a> {
+    client: &'a crate::client::Client,
+}
+
+impl<'a> AdministrativeCalendar<'a> {
+    /// 祝日データを渡して初期化します。
+    pub fn new(client: &'a crate::client::Client) -> Self {
+        Self { client }
+    }
+
+    /// カレンダーが参照している `Client` を取得します。
+    pub fn client(&self) -> &'a crate::client::Client {
+        self.client
+    }
+
+    /// 年末年始の休日 (12月29日から翌年1月3日まで) かどうかを判定します。
+    pub fn is_year_end_holiday(date: NaiveDate) -> bool {
+        YEAR_END_HOLIDAYS.contains(&(date.month(), date.day()))
+    }
+}
+
+impl crate::business::BusinessCalendar for AdministrativeCalendar<'_> {
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        self.client.is_day_off(date) || Self::is_year_end_holiday(date)
+    }
+
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        self.client.coverage()
+    }
+
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.client.get_holiday_detail(date)
+    }
+
+    fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        let mut reasons = crate::business::BusinessCalendar::explain(self.client, date);
+        if Self::is_year_end_holiday(date) {
+            reasons.push(crate::explain::Reason::YearEnd {
+                regulation: "行政機関の休日に関する法律第1条",
+            });
+        }
+        reasons
+    }
+
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end <= start {
+            return 0;
+        }
+
+        let year_end = crate::bank::year_end_holidays_between(&YEAR_END_HOLIDAYS, start, end)
+            .filter(|date| !self.client.is_day_off(*date))
+            .count() as i64;
+
+        self.client.count_days_off(start, end) + year_end
+    }
+}
+
+/// 行政機関の年末年始の休日 (12月29日から翌年1月3日まで) の月日です。
+const YEAR_END_HOLIDAYS: [(u32, u32); 6] = [(1, 1), (1, 2), (1, 3), (12, 29), (12, 30), (12, 31)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::BusinessCalendar;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_year_end_holidays() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = AdministrativeCalendar::new(&client);
+
+        // 2025年12月29日は月曜日
+        assert!(calendar.is_day_off(ymd(2025, 12, 29)));
+        assert_eq!(
+            calendar.next_business_day(ymd(2025, 12, 26)).unwrap(),
+            ymd(2026, 1, 5)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_days_off_matches_day_by_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = AdministrativeCalendar::new(&client);
+
+        crate::business::assert_count_days_off_matches_day_by_day(
+            &calendar,
+            ymd(2019, 12, 15),
+            ymd(2026, 1, 4),
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::NaiveDate;
+
+/// 休日に挟まれた営業日 (挟まれた平日) です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bridge {
+    /// 挟まれた営業日 (日付順)
+    pub days: Vec<NaiveDate>,
+
+    /// 直前の連休
+    pub before: crate::runs::DayOffRun,
+
+    /// 直後の連休
+    pub after: crate::runs::DayOffRun,
+}
+
+impl Bridge {
+    /// 挟まれた営業日を休みにした場合の、前後の連休と合わせた日数を取得します。
+    pub fn combined_len(&self) -> i64 {
+        (self.after.end - self.before.start).num_days() + 1
+    }
+}
+
+/// 期間と重なる連休の間で、`max_gap` 日以下の営業日を取得します。
+pub(crate) fn bridges<C: crate::business::BusinessCalendar + ?Sized>(
+    calendar: &C,
+    period: std::ops::RangeInclusive<NaiveDate>,
+    max_gap: usize,
+) -> Result<Vec<Bridge>, crate::error::Error> {
+    let runs = crate::runs::day_off_runs(calendar, period)?;
+
+    Ok(runs
+        .iter()
+        .zip(runs.iter().skip(1))
+        .filter_map(|(before, after)| {
+            let days: Vec<NaiveDate> = before
+                .end
+                .iter_days()
+                .skip(1)
+                .take_while(|date| *date < after.start)
+                .collect();
+
+            (!days.is_empty() && days.len() <= max_gap).then(|| Bridge {
+                days,
+                before: before.clone(),
+                after: after.clone(),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bridge_days() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let bridges = client
+            .bridge_days(ymd(2026, 1, 1)..=ymd(2026, 12, 31), 1)
+            .unwrap();
+
+        // 2026年は1月2日 (金)・8月10日 (月)・11月2日 (月) が休日に挟まれています
+        let days: Vec<NaiveDate> = bridges
+            .iter()
+            .flat_map(|bridge| bridge.days.clone())
+            .collect();
+        assert_eq!(
+            days,
+            vec![ymd(2026, 1, 2), ymd(2026, 8, 10), ymd(2026, 11, 2)]
+        );
+        assert_eq!(bridges[0].combined_len(), 4);
+        assert_eq!(bridges[2].after.holidays[0].name, "文化の日");
+    }
+
+    #[tokio::test]
+    async fn test_bridge_days_max_gap() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let bridges = client
+            .bridge_days(ymd(2026, 4, 1)..=ymd(2026, 5, 31), 2)
+            .unwrap();
+
+        // 昭和の日 (4月29日・水) の前の27日 (月)・28日 (火) と、
+        // ゴールデンウィークの前後の4月30日 (木)・5月1日 (金) と7日 (木)・8日 (金)
+        assert_eq!(bridges.len(), 3);
+        assert_eq!(bridges[0].days, vec![ymd(2026, 4, 27), ymd(2026, 4, 28)]);
+        assert_eq!(bridges[1].days, vec![ymd(2026, 4, 30), ymd(2026, 5, 1)]);
+        assert_eq!(bridges[1].combined_len(), 8);
+        assert_eq!(bridges[2].days, vec![ymd(2026, 5, 7), ymd(2026, 5, 8)]);
+    }
+}
@@ -0,0 +1,894 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 休日の判定をもとに営業日の計算を行うトレイトです。
+///
+/// `is_day_off()` と `coverage()` を実装すると、営業日の計算を行うメソッドが利用できます。
+/// 祝日データの範囲 (`coverage()`) の外の日付が必要になった場合、計算は
+/// `crate::error::Error::OutOfRange` を返します。
+///
+/// ### 必須メソッド
+///
+/// - `is_day_off()`: `chrono::NaiveDate` を渡して休日かどうかを判定します。
+/// - `coverage()`: 休日を判定できる日付の範囲を取得します。
+///
+/// ### 提供メソッド
+///
+/// - `is_business_day()`: `chrono::NaiveDate` を渡して営業日かどうかを判定します。
+/// - `get_holiday_detail()`: `chrono::NaiveDate` を渡して祝日を取得します (既定の実装は常に `None` です)。
+/// - `explain()`: `chrono::NaiveDate` を渡して、その日が休日になる理由をすべて取得します。
+/// - `count_days_off()`: 期間内の休日の日数を数えます。
+/// - `next_business_day()`: 翌営業日を取得します。
+/// - `previous_business_day()`: 前営業日を取得します。
+/// - `add_business_days()`: N 営業日後 (負の場合は前) の日付を取得します。
+/// - `business_days_between()`: 期間内の営業日の日数を数えます。
+/// - `adjust()`: 営業日調整規則に従って、休日を営業日に調整します。
+/// - `business_days_of_month()`: 月の営業日をすべて取得します。
+/// - `count_business_days_in_month()`: 月の営業日の日数を数えます。
+/// - `nth_business_day_of_month()`: 月の第 N 営業日 (負の場合は最終営業日から数えます) を取得します。
+/// - `last_business_day_of_month()`: 月の最終営業日を取得します。
+/// - `business_day_index_in_month()`: 日付が月の第何営業日かを取得します。
+/// - `adjusted_day_of_month()`: 月の指定した日を、営業日調整規則に従って調整します。
+/// - `day_off_runs()`: 期間と重なる連休をすべて取得します。
+/// - `break_containing()`: 日付を含む連休を取得します。
+/// - `golden_week()`: 年を渡してゴールデンウィークを取得します。
+/// - `silver_week()`: 年を渡してシルバーウィークを取得します。
+/// - `bridge_days()`: 休日に挟まれた営業日を取得します。
+/// - `classify_days()`: 期間内の日付を、種別を判定しながら順に返すイテレーターを取得します。
+pub trait BusinessCalendar {
+    /// `chrono::NaiveDate` を渡して休日かどうかを判定します。
+    fn is_day_off(&self, date: NaiveDate) -> bool;
+
+    /// 休日を判定できる日付の範囲を取得します。
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate>;
+
+    /// `chrono::NaiveDate` を渡して営業日かどうかを判定します。
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_day_off(date)
+    }
+
+    /// `chrono::NaiveDate` を渡して、その日の祝日を取得します。
+    ///
+    /// 連休に含まれる祝日の取得などに使用します。祝日データを持たないカレンダーは既定の実装 (常に `None`) のままで構いません。
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        let _ = date;
+        None
+    }
+
+    /// `chrono::NaiveDate` を渡して、その日が休日になる理由をすべて取得します。
+    ///
+    /// 営業日の場合は空です。ただし、出勤日に設定した日は `crate::explain::Reason::WorkingDay` を返し、
+    /// 打ち消された休日の理由があれば続けて返します。
+    ///
+    /// 既定の実装は、休日である土曜日・日曜日と `get_holiday_detail()` の祝日を理由とします。
+    /// 独自の休日を持つカレンダーは、その理由を加えて実装します。
+    fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        let weekend = self.is_day_off(date)
+            && matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun);
+        crate::explain::calendar_reasons(self, date, weekend)
+    }
+
+    /// `start` 以上 `end` 未満の期間に含まれる休日の日数を数えます。
+    ///
+    /// 既定の実装は1日ずつ判定します。範囲の検証は行いません。
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        start
+            .iter_days()
+            .take_while(|date| *date < end)
+            .filter(|date| self.is_day_off(*date))
+            .count() as i64
+    }
+
+    /// `date` より後で最も近い営業日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 2026年のゴールデンウィーク明け
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 2).ok_or("存在しない日付です".to_string())?;
+    ///     let next = client.next_business_day(date)?;
+    ///
+    ///     println!("2026年 5月 2日 の翌営業日は {}", next);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn next_business_day(&self, date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        let mut cursor = date;
+        loop {
+            cursor = succ(cursor)?;
+            ensure_covered(self, cursor)?;
+            if self.is_business_day(cursor) {
+                return Ok(cursor);
+            }
+        }
+    }
+
+    /// `date` より前で最も近い営業日を取得します。
+    fn previous_business_day(&self, date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        let mut cursor = date;
+        loop {
+            cursor = pred(cursor)?;
+            ensure_covered(self, cursor)?;
+            if self.is_business_day(cursor) {
+                return Ok(cursor);
+            }
+        }
+    }
+
+    /// `date` から `days` 営業日後の日付を取得します。`days` が負の場合は前にさかのぼります。
+    ///
+    /// `date` 自身は数えません。`days` が `0` の場合は `date` をそのまま返します。
+    ///
+    /// 期間内の営業日を `count_days_off()` でまとめて数えながら進みます。
+    /// `count_days_off()` の既定の実装は1日ずつ判定するため、速さはカレンダーの実装によります。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 4, 28).ok_or("存在しない日付です".to_string())?;
+    ///     let due = client.add_business_days(date, 5)?;
+    ///
+    ///     println!("2026年 4月 28日 の5営業日後は {}", due);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn add_business_days(
+        &self,
+        date: NaiveDate,
+        days: i64,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        if days == 0 {
+            return Ok(date);
+        }
+
+        let step = days.signum();
+        let mut remaining = days.abs();
+        let mut cursor = date;
+
+        // `remaining` 日の間に営業日は高々 `remaining` 日しかないため、行き過ぎることはありません。
+        while remaining > 0 {
+            let target = shift(cursor, step * remaining)?;
+            ensure_covered(self, target)?;
+
+            let counted = if step > 0 {
+                self.business_days_between(succ(cursor)?, succ(target)?)?
+            } else {
+                self.business_days_between(target, cursor)?
+            };
+
+            remaining -= counted;
+            cursor = target;
+        }
+
+        // 最後に数えた営業日より先の休日を戻します。
+        while self.is_day_off(cursor) {
+            cursor = shift(cursor, -step)?;
+        }
+
+        Ok(cursor)
+    }
+
+    /// `start` 以上 `end` 未満の期間に含まれる営業日の日数を数えます。
+    ///
+    /// `end` が `start` より前の場合は、`end` 以上 `start` 未満の営業日の日数を負の値で返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     let days = client.business_days_between(start, end)?;
+    ///     println!("2026年 5月 の営業日は {} 日です", days);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn business_days_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<i64, crate::error::Error> {
+        if end < start {
+            return self.business_days_between(end, start).map(|days| -days);
+        }
+        if start == end {
+            return Ok(0);
+        }
+
+        ensure_covered(self, start)?;
+        ensure_covered(self, pred(end)?)?;
+
+        Ok((end - start).num_days() - self.count_days_off(start, end))
+    }
+
+    /// `date` が休日の場合に、営業日調整規則 (`convention`) に従って営業日に調整します。
+    ///
+    /// `date` が営業日の場合と、`crate::convention::BusinessDayConvention::Unadjusted` の場合は `date` をそのまま返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, convention::BusinessDayConvention};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 2026年5月31日は日曜日
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 31).ok_or("存在しない日付です".to_string())?;
+    ///     let adjusted = client.adjust(date, BusinessDayConvention::ModifiedFollowing)?;
+    ///
+    ///     println!("2026年 5月 31日 の調整後の日付は {}", adjusted);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn adjust(
+        &self,
+        date: NaiveDate,
+        convention: crate::convention::BusinessDayConvention,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        use crate::convention::BusinessDayConvention;
+
+        if convention == BusinessDayConvention::Unadjusted {
+            return Ok(date);
+        }
+
+        ensure_covered(self, date)?;
+        if self.is_business_day(date) {
+            return Ok(date);
+        }
+
+        match convention {
+            BusinessDayConvention::Following => self.next_business_day(date),
+            BusinessDayConvention::Preceding => self.previous_business_day(date),
+            BusinessDayConvention::ModifiedFollowing => {
+                let following = self.next_business_day(date)?;
+                if following.month() == date.month() {
+                    Ok(following)
+                } else {
+                    self.previous_business_day(date)
+                }
+            }
+            BusinessDayConvention::ModifiedPreceding => {
+                let preceding = self.previous_business_day(date)?;
+                if preceding.month() == date.month() {
+                    Ok(preceding)
+                } else {
+                    self.next_business_day(date)
+                }
+            }
+            BusinessDayConvention::Unadjusted => Ok(date),
+        }
+    }
+
+    /// 年月を渡して、その月の営業日をすべて取得します。
+    ///
+    /// 月の一部でも祝日データの範囲外の場合は `crate::error::Error::OutOfRange` を返します。
+    fn business_days_of_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<NaiveDate>, crate::error::Error> {
+        let (first, last) = month_bounds(year, month)?;
+        ensure_covered(self, first)?;
+        ensure_covered(self, last)?;
+
+        Ok(first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .filter(|date| self.is_business_day(*date))
+            .collect())
+    }
+
+    /// 年月を渡して、その月の営業日の日数を数えます。
+    fn count_business_days_in_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<i64, crate::error::Error> {
+        let (first, last) = month_bounds(year, month)?;
+        self.business_days_between(first, succ(last)?)
+    }
+
+    /// 年月を渡して、その月の第 `nth` 営業日を取得します。
+    ///
+    /// `nth` が負の場合は月末から数えます (`-1` は最終営業日)。該当する営業日がない場合は `None` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     if let Some(date) = client.nth_business_day_of_month(2026, 5, 5)? {
+    ///         println!("2026年 5月 の第5営業日は {}", date);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn nth_business_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+        nth: i32,
+    ) -> Result<Option<NaiveDate>, crate::error::Error> {
+        if nth == 0 {
+            return Err(crate::error::Error::InvalidDate(
+                "第0営業日は指定できません".to_string(),
+            ));
+        }
+
+        let days = self.business_days_of_month(year, month)?;
+        let index = if nth > 0 {
+            nth as usize - 1
+        } else {
+            match days.len().checked_sub(nth.unsigned_abs() as usize) {
+                Some(index) => index,
+                None => return Ok(None),
+            }
+        };
+
+        Ok(days.get(index).copied())
+    }
+
+    /// 年月を渡して、その月の最終営業日を取得します。月に営業日がない場合は `None` を返します。
+    fn last_business_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Option<NaiveDate>, crate::error::Error> {
+        self.nth_business_day_of_month(year, month, -1)
+    }
+
+    /// `date` がその月の第何営業日かを取得します。`date` が休日の場合は `None` を返します。
+    fn business_day_index_in_month(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<u32>, crate::error::Error> {
+        ensure_covered(self, date)?;
+        if self.is_day_off(date) {
+            return Ok(None);
+        }
+
+        let (first, _) = month_bounds(date.year(), date.month())?;
+        ensure_covered(self, first)?;
+        Ok(Some((self.business_days_between(first, date)? + 1) as u32))
+    }
+
+    /// 年月と日を渡して、その日を営業日調整規則 (`convention`) に従って調整します。
+    ///
+    /// `day` が月の日数より大きい場合は月末日を使用します (例: 2月の31日は2月末日)。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::{client::Client, convention::BusinessDayConvention};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 毎月25日、休日の場合は前営業日
+    ///     let date = client.adjusted_day_of_month(2026, 10, 25, BusinessDayConvention::Preceding)?;
+    ///     println!("2026年 10月 の支払日は {}", date);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn adjusted_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        convention: crate::convention::BusinessDayConvention,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        if day == 0 {
+            return Err(crate::error::Error::InvalidDate(format!(
+                "不正な日付です: {}年 {}月 {}日",
+                year, month, day
+            )));
+        }
+
+        let (_, last) = month_bounds(year, month)?;
+        let date = NaiveDate::from_ymd_opt(year, month, day.min(last.day())).unwrap_or(last);
+        self.adjust(date, convention)
+    }
+
+    /// 期間と重なる連休 (休日が連続する期間) を日付順にすべて取得します。
+    ///
+    /// 期間の端にかかる連休は、期間の外側も含めた連休全体を返します。1日だけの休日も連休として返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 1, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     for run in client.day_off_runs(start..=end)?.iter().filter(|run| run.days() >= 3) {
+    ///         println!("{} 〜 {} ({} 連休)", run.start, run.end, run.days());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn day_off_runs(
+        &self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+    ) -> Result<Vec<crate::runs::DayOffRun>, crate::error::Error> {
+        crate::runs::day_off_runs(self, period)
+    }
+
+    /// `date` を含む連休を取得します。`date` が営業日の場合は `None` を返します。
+    fn break_containing(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<crate::runs::DayOffRun>, crate::error::Error> {
+        crate::runs::break_containing(self, date)
+    }
+
+    /// 年を渡して、その年のゴールデンウィーク (5月3日を含む3日以上の連休) を取得します。
+    fn golden_week(
+        &self,
+        year: i32,
+    ) -> Result<Option<crate::runs::DayOffRun>, crate::error::Error> {
+        let date = NaiveDate::from_ymd_opt(year, 5, 3).ok_or_else(|| {
+            crate::error::Error::InvalidDate(format!("不正な日付です: {}年 5月 3日", year))
+        })?;
+
+        Ok(self
+            .break_containing(date)?
+            .filter(|run| run.name() == Some(crate::runs::NamedBreak::GoldenWeek)))
+    }
+
+    /// 年を渡して、その年のシルバーウィーク (敬老の日と秋分の日をともに含む連休) を取得します。
+    ///
+    /// 祝日の判定に `get_holiday_detail()` を使用します。
+    fn silver_week(
+        &self,
+        year: i32,
+    ) -> Result<Option<crate::runs::DayOffRun>, crate::error::Error> {
+        let (first, last) = month_bounds(year, 9)?;
+        ensure_covered(self, first)?;
+        ensure_covered(self, last)?;
+
+        let respect_for_the_aged_day =
+            first
+                .iter_days()
+                .take_while(|date| *date <= last)
+                .find(|date| {
+                    self.get_holiday_detail(*date)
+                        .and_then(|holiday| holiday.id())
+                        == Some(crate::holiday::HolidayId::RespectForTheAgedDay)
+                });
+
+        match respect_for_the_aged_day {
+            Some(date) => Ok(self
+                .break_containing(date)?
+                .filter(|run| run.name() == Some(crate::runs::NamedBreak::SilverWeek))),
+            None => Ok(None),
+        }
+    }
+
+    /// 期間と重なる連休の間にある、`max_gap` 日以下の営業日 (休日に挟まれた営業日) を取得します。
+    ///
+    /// 祝日の木曜日と週末に挟まれた金曜日や、ゴールデンウィーク中の平日などが該当します。
+    /// 前後の連休も合わせて返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 1, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     for bridge in client.bridge_days(start..=end, 1)? {
+    ///         println!("{:?} を休むと {} 連休", bridge.days, bridge.combined_len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn bridge_days(
+        &self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+        max_gap: usize,
+    ) -> Result<Vec<crate::bridge::Bridge>, crate::error::Error> {
+        crate::bridge::bridges(self, period, max_gap)
+    }
+
+    /// 期間内の日付を、種別 (平日・土曜日・日曜日・祝日・休業日など) を判定しながら順に返すイテレーターを取得します。
+    ///
+    /// `business_days()` や `days_off()` で営業日・休日に絞り込めます。
+    /// 期間の一部でも範囲外の場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{business::BusinessCalendar, client::Client, overlay::OverlayCalendar};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 8, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 8, 31).ok_or("存在しない日付です".to_string())?;
+    ///     let calendar = OverlayCalendar::new(&client)
+    ///         .with_closures_between(start..=end, "夏季休業");
+    ///
+    ///     for day in calendar.classify_days(start..=end)?.days_off() {
+    ///         println!("{} | {:?}", day.date, day.kind);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn classify_days(
+        &self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+    ) -> Result<crate::days::Days<'_, Self>, crate::error::Error> {
+        crate::days::Days::new(self, period)
+    }
+}
+
+impl<C: BusinessCalendar + ?Sized> BusinessCalendar for &C {
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        (**self).is_day_off(date)
+    }
+
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        (**self).coverage()
+    }
+
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        (**self).get_holiday_detail(date)
+    }
+
+    fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        (**self).explain(date)
+    }
+
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        (**self).count_days_off(start, end)
+    }
+}
+
+impl BusinessCalendar for crate::client::Client {
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        crate::client::Client::is_day_off(self, date)
+    }
+
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        crate::client::Client::coverage(self)
+    }
+
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        crate::client::Client::get_holiday_detail(self, date)
+    }
+
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        crate::business::BusinessCalendar::count_days_off(
+            &crate::calendar::Calendar::new(self),
+            start,
+            end,
+        )
+    }
+}
+
+/// `start` 以上 `end` 未満の期間で、曜日が条件を満たす日数を数えます。
+pub(crate) fn count_weekdays_matching(
+    start: NaiveDate,
+    end: NaiveDate,
+    predicate: impl Fn(chrono::Weekday) -> bool,
+) -> i64 {
+    if end <= start {
+        return 0;
+    }
+
+    let days = (end - start).num_days();
+    let per_week = start
+        .iter_days()
+        .take(7)
+        .filter(|date| predicate(date.weekday()))
+        .count() as i64;
+    let rest = start
+        .iter_days()
+        .take((days % 7) as usize)
+        .filter(|date| predicate(date.weekday()))
+        .count() as i64;
+
+    days / 7 * per_week + rest
+}
+
+/// 日付が休日を判定できる範囲に含まれているか確認します。
+pub(crate) fn ensure_covered<C: BusinessCalendar + ?Sized>(
+    calendar: &C,
+    date: NaiveDate,
+) -> Result<(), crate::error::Error> {
+    let coverage = calendar.coverage();
+    if coverage.contains(&date) {
+        Ok(())
+    } else {
+        Err(crate::error::Error::OutOfRange(format!(
+            "{} は祝日データの範囲 ({} 〜 {}) に含まれません",
+            date,
+            coverage.start(),
+            coverage.end()
+        )))
+    }
+}
+
+/// 年月を渡して、その月の初日と末日を取得します。
+pub(crate) fn month_bounds(
+    year: i32,
+    month: u32,
+) -> Result<(NaiveDate, NaiveDate), crate::error::Error> {
+    let invalid =
+        || crate::error::Error::InvalidDate(format!("不正な年月です: {}年 {}月", year, month));
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(invalid)?;
+    let last = first
+        .checked_add_months(chrono::Months::new(1))
+        .and_then(|date| date.pred_opt())
+        .ok_or_else(invalid)?;
+    Ok((first, last))
+}
+
+fn shift(date: NaiveDate, days: i64) -> Result<NaiveDate, crate::error::Error> {
+    date.checked_add_signed(chrono::Duration::days(days))
+        .ok_or_else(|| {
+            crate::error::Error::OutOfRange(format!("{} から {} 日移動できません", date, days))
+        })
+}
+
+fn succ(date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+    shift(date, 1)
+}
+
+fn pred(date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+    shift(date, -1)
+}
+
+/// `count_days_off()` の結果が、1日ずつ判定して数えた結果と一致することを確認します。逆順の期間は0日であることも確認します。
+#[cfg(test)]
+pub(crate) fn assert_count_days_off_matches_day_by_day<C: BusinessCalendar + ?Sized>(
+    calendar: &C,
+    start: NaiveDate,
+    end: NaiveDate,
+) {
+    let expected = start
+        .iter_days()
+        .take_while(|date| *date < end)
+        .filter(|date| calendar.is_day_off(*date))
+        .count() as i64;
+
+    assert_eq!(
+        calendar.count_days_off(start, end),
+        expected,
+        "{} 〜 {}",
+        start,
+        end
+    );
+
+    // 期間が逆順の場合は0日です。
+    assert_eq!(
+        calendar.count_days_off(end, start),
+        0,
+        "{} 〜 {}",
+        end,
+        start
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_next_business_day_skips_golden_week() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let next = client.next_business_day(ymd(2026, 5, 1)).unwrap();
+        assert_eq!(next, ymd(2026, 5, 7));
+    }
+
+    #[tokio::test]
+    async fn test_previous_business_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let previous = client.previous_business_day(ymd(2026, 5, 7)).unwrap();
+        assert_eq!(previous, ymd(2026, 5, 1));
+    }
+
+    #[tokio::test]
+    async fn test_add_business_days_matches_day_by_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let start = ymd(2025, 12, 20);
+
+        let mut expected = start;
+        for days in 1..=200 {
+            expected = client.next_business_day(expected).unwrap();
+            let actual = client.add_business_days(start, days).unwrap();
+            assert_eq!(actual, expected, "{} 営業日後", days);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_business_days_backward() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let date = client.add_business_days(ymd(2026, 5, 7), -2).unwrap();
+        assert_eq!(date, ymd(2026, 4, 30));
+    }
+
+    #[tokio::test]
+    async fn test_business_days_between() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let days = client
+            .business_days_between(ymd(2026, 5, 1), ymd(2026, 6, 1))
+            .unwrap();
+        // 2026年5月は平日21日のうち、4日・5日・6日が祝日です。
+        assert_eq!(days, 18);
+
+        let days = client
+            .business_days_between(ymd(2026, 6, 1), ymd(2026, 5, 1))
+            .unwrap();
+        assert_eq!(days, -18);
+    }
+
+    #[tokio::test]
+    async fn test_adjust() {
+        use crate::convention::BusinessDayConvention;
+
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        // 2026年5月30日 (土)・31日 (日)
+        let date = ymd(2026, 5, 31);
+        assert_eq!(
+            client
+                .adjust(date, BusinessDayConvention::Following)
+                .unwrap(),
+            ymd(2026, 6, 1)
+        );
+        assert_eq!(
+            client
+                .adjust(date, BusinessDayConvention::ModifiedFollowing)
+                .unwrap(),
+            ymd(2026, 5, 29)
+        );
+        assert_eq!(
+            client
+                .adjust(date, BusinessDayConvention::Preceding)
+                .unwrap(),
+            ymd(2026, 5, 29)
+        );
+        assert_eq!(
+            client
+                .adjust(date, BusinessDayConvention::Unadjusted)
+                .unwrap(),
+            date
+        );
+
+        // 2026年8月1日 (土)
+        assert_eq!(
+            client
+                .adjust(ymd(2026, 8, 1), BusinessDayConvention::ModifiedPreceding)
+                .unwrap(),
+            ymd(2026, 8, 3)
+        );
+        assert_eq!(
+            client
+                .adjust(ymd(2026, 5, 4), BusinessDayConvention::ModifiedFollowing)
+                .unwrap(),
+            ymd(2026, 5, 7)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_business_days_of_month() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        assert_eq!(client.count_business_days_in_month(2026, 5).unwrap(), 18);
+        assert_eq!(
+            client.nth_business_day_of_month(2026, 5, 1).unwrap(),
+            Some(ymd(2026, 5, 1))
+        );
+        assert_eq!(
+            client.nth_business_day_of_month(2026, 5, 2).unwrap(),
+            Some(ymd(2026, 5, 7))
+        );
+        assert_eq!(
+            client.last_business_day_of_month(2026, 5).unwrap(),
+            Some(ymd(2026, 5, 29))
+        );
+        assert_eq!(client.nth_business_day_of_month(2026, 5, 19).unwrap(), None);
+        assert!(client.nth_business_day_of_month(2026, 5, 0).is_err());
+        assert!(client.business_days_of_month(2026, 13).is_err());
+
+        assert_eq!(
+            client.business_day_index_in_month(ymd(2026, 5, 7)).unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            client
+                .business_day_index_in_month(ymd(2026, 5, 29))
+                .unwrap(),
+            Some(18)
+        );
+        assert_eq!(
+            client.business_day_index_in_month(ymd(2026, 5, 3)).unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_adjusted_day_of_month() {
+        use crate::convention::BusinessDayConvention;
+
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        // 2026年10月25日は日曜日
+        assert_eq!(
+            client
+                .adjusted_day_of_month(2026, 10, 25, BusinessDayConvention::Preceding)
+                .unwrap(),
+            ymd(2026, 10, 23)
+        );
+        // 2026年2月は28日 (土) が末日
+        assert_eq!(
+            client
+                .adjusted_day_of_month(2026, 2, 31, BusinessDayConvention::Preceding)
+                .unwrap(),
+            ymd(2026, 2, 27)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_out_of_range() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let result = client.add_business_days(ymd(2026, 12, 1), 30);
+        assert!(matches!(result, Err(crate::error::Error::OutOfRange(_))));
+    }
+}
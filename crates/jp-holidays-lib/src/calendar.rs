@@ -0,0 +1,254 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 1週間のうち、どの曜日を休日とするかの設定です。
+///
+/// 既定値は土曜日と日曜日を休日とします。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::Weekday;
+/// use jp_holidays_lib::calendar::WorkWeek;
+///
+/// // 水曜日と日曜日が休みの店舗
+/// let work_week = WorkWeek::new([Weekday::Wed, Weekday::Sun]);
+///
+/// assert!(work_week.is_day_off(Weekday::Wed));
+/// assert!(!work_week.is_day_off(Weekday::Sat));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorkWeek {
+    /// 月曜日から順に、休日であれば `true`
+    days_off: [bool; 7],
+}
+
+impl WorkWeek {
+    /// 休日とする曜日を渡して初期化します。
+    pub fn new(days_off: impl IntoIterator<Item = chrono::Weekday>) -> Self {
+        let mut flags = [false; 7];
+        for weekday in days_off {
+            flags[weekday.num_days_from_monday() as usize] = true;
+        }
+        Self { days_off: flags }
+    }
+
+    /// 曜日を渡して休日かどうかを判定します。
+    pub fn is_day_off(&self, weekday: chrono::Weekday) -> bool {
+        self.days_off[weekday.num_days_from_monday() as usize]
+    }
+
+    /// 休日とする曜日を月曜日から順に取得します。
+    pub fn days_off(&self) -> impl Iterator<Item = chrono::Weekday> + '_ {
+        [
+            chrono::Weekday::Mon,
+            chrono::Weekday::Tue,
+            chrono::Weekday::Wed,
+            chrono::Weekday::Thu,
+            chrono::Weekday::Fri,
+            chrono::Weekday::Sat,
+            chrono::Weekday::Sun,
+        ]
+        .into_iter()
+        .filter(|weekday| self.is_day_off(*weekday))
+    }
+}
+
+impl Default for WorkWeek {
+    fn default() -> Self {
+        Self::new([chrono::Weekday::Sat, chrono::Weekday::Sun])
+    }
+}
+
+/// 曜日による休日の設定と、国民の祝日を組み合わせたカレンダーです。
+///
+/// 期間ごとに異なる `WorkWeek` を設定できます。期間が重なる場合は後から設定したものが優先されます。
+/// 営業日の計算は `crate::business::BusinessCalendar` のメソッドで行います。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use jp_holidays_lib::{
+///     business::BusinessCalendar,
+///     calendar::{Calendar, WorkWeek},
+///     client::Client,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     // 日曜日のみ休みで、2026年8月は土曜日も休みの工場
+///     let start = NaiveDate::from_ymd_opt(2026, 8, 1).ok_or("存在しない日付です".to_string())?;
+///     let end = NaiveDate::from_ymd_opt(2026, 8, 31).ok_or("存在しない日付です".to_string())?;
+///     let calendar = Calendar::new(&client)
+///         .with_work_week(WorkWeek::new([Weekday::Sun]))
+///         .with_work_week_between(start..=end, WorkWeek::default());
+///
+///     let date = NaiveDate::from_ymd_opt(2026, 7, 17).ok_or("存在しない日付です".to_string())?;
+///     println!("2026年 7月 17日 の翌営業日は {}", calendar.next_business_day(date)?);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Calendar<'a> {
+    client: &'a crate::client::Client,
+    work_week: WorkWeek,
+    periods: Vec<(std::ops::RangeInclusive<NaiveDate>, WorkWeek)>,
+}
+
+impl<'a> Calendar<'a> {
+    /// 土曜日と日曜日を休日とするカレンダーを初期化します。
+    pub fn new(client: &'a crate::client::Client) -> Self {
+        Self {
+            client,
+            work_week: WorkWeek::default(),
+            periods: Vec::new(),
+        }
+    }
+
+    /// 期間の指定がない日に適用する `WorkWeek` を設定します。
+    pub fn with_work_week(mut self, work_week: WorkWeek) -> Self {
+        self.work_week = work_week;
+        self
+    }
+
+    /// 指定した期間に適用する `WorkWeek` を設定します。
+    pub fn with_work_week_between(
+        mut self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+        work_week: WorkWeek,
+    ) -> Self {
+        self.periods.push((period, work_week));
+        self
+    }
+
+    /// カレンダーが参照している `Client` を取得します。
+    pub fn client(&self) -> &'a crate::client::Client {
+        self.client
+    }
+
+    /// 指定した日に適用される `WorkWeek` を取得します。
+    pub fn work_week_on(&self, date: NaiveDate) -> WorkWeek {
+        self.periods
+            .iter()
+            .rev()
+            .find(|(period, _)| period.contains(&date))
+            .map(|(_, work_week)| *work_week)
+            .unwrap_or(self.work_week)
+    }
+
+    /// `start` 以上 `end` 未満の期間を、適用される `WorkWeek` が変わる日で区切ります。
+    fn segments(&self, start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut boundaries: std::collections::BTreeSet<NaiveDate> = self
+            .periods
+            .iter()
+            .flat_map(|(period, _)| [Some(*period.start()), period.end().succ_opt()])
+            .flatten()
+            .filter(|date| start < *date && *date < end)
+            .collect();
+        boundaries.insert(start);
+        boundaries.insert(end);
+
+        boundaries
+            .iter()
+            .zip(boundaries.iter().skip(1))
+            .map(|(from, to)| (*from, *to))
+            .collect()
+    }
+}
+
+impl crate::business::BusinessCalendar for Calendar<'_> {
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        self.work_week_on(date).is_day_off(date.weekday()) || self.client.is_holiday(date)
+    }
+
+    fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        let weekend = self.work_week_on(date).is_day_off(date.weekday());
+        crate::explain::calendar_reasons(self.client, date, weekend)
+    }
+
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        self.client.coverage()
+    }
+
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.client.get_holiday_detail(date)
+    }
+
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end <= start {
+            return 0;
+        }
+
+        self.segments(start, end)
+            .into_iter()
+            .map(|(from, to)| {
+                let work_week = self.work_week_on(from);
+                let weekly = crate::business::count_weekdays_matching(from, to, |weekday| {
+                    work_week.is_day_off(weekday)
+                });
+                let holidays = self
+                    .client
+                    .list_holidays()
+                    .range(from..to)
+                    .filter(|(date, _)| !work_week.is_day_off(date.weekday()))
+                    .count() as i64;
+                weekly + holidays
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::BusinessCalendar;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_custom_work_week() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = Calendar::new(&client).with_work_week(WorkWeek::new([chrono::Weekday::Wed]));
+
+        // 2026年7月22日は水曜日
+        assert!(calendar.is_day_off(ymd(2026, 7, 22)));
+        // 2026年7月25日は土曜日
+        assert!(!calendar.is_day_off(ymd(2026, 7, 25)));
+        // 2026年7月20日は海の日
+        assert!(calendar.is_day_off(ymd(2026, 7, 20)));
+    }
+
+    #[tokio::test]
+    async fn test_work_week_between() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = Calendar::new(&client)
+            .with_work_week(WorkWeek::new([chrono::Weekday::Sun]))
+            .with_work_week_between(ymd(2026, 8, 1)..=ymd(2026, 8, 31), WorkWeek::default());
+
+        assert!(!calendar.is_day_off(ymd(2026, 7, 25)));
+        assert!(calendar.is_day_off(ymd(2026, 8, 1)));
+        assert_eq!(
+            calendar.next_business_day(ymd(2026, 8, 28)).unwrap(),
+            ymd(2026, 8, 31)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_days_off_matches_day_by_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = Calendar::new(&client)
+            .with_work_week(WorkWeek::new([chrono::Weekday::Sun, chrono::Weekday::Wed]))
+            .with_work_week_between(ymd(2026, 3, 10)..=ymd(2026, 6, 2), WorkWeek::default());
+
+        crate::business::assert_count_days_off_matches_day_by_day(
+            &calendar,
+            ymd(2025, 11, 3),
+            ymd(2026, 12, 1),
+        );
+    }
+}
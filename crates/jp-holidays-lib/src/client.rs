@@ -15,8 +15,56 @@ use chrono::{Datelike, NaiveDate};
 /// - `is_day_off()`: `chrono::NaiveDate` を渡して休日かどうかを判定します。
 /// - `is_day_off_ymd.()`: 年月日を渡して休日かどうかを判定します。
 /// - `list_holidays()`: 公開されている祝日をすべて取得します (`BTreeMap<NaiveDate, String>`)
+/// - `get_holiday_detail()`: `chrono::NaiveDate` を渡して種別付きの祝日を取得します。
+/// - `get_holiday_detail_ymd()`: 年月日を渡して種別付きの祝日を取得します。
+/// - `list_holiday_details()`: 公開されている祝日を種別付きですべて取得します (`BTreeMap<NaiveDate, Holiday>`)
+/// - `get_substituted_holiday()`: 振替休日の日付を渡して、振り替えられた元の祝日を取得します。
+/// - `get_substitute_holiday()`: 祝日の日付を渡して、その祝日の振替休日を取得します。
+/// - `list_substitute_holidays()`: 振替休日と元の祝日の組をすべて取得します。
+/// - `get_holiday_display_name()`: 振替元を含めた表示用の祝日名を取得します。
+/// - `get_holiday_localized()`: `chrono::NaiveDate` と言語を渡して祝日名を取得します。
+/// - `get_holiday_localized_ymd()`: 年月日と言語を渡して祝日名を取得します。
+/// - `get_holiday_id()`: `chrono::NaiveDate` を渡して祝日の ID を取得します。
+/// - `list_holidays_by_id()`: `HolidayId` を渡して、名称の変更をまたいでその祝日をすべて取得します。
+/// - `holidays_in_year()`: 年を渡して、その年の祝日を取得します。
+/// - `holidays_in_month()`: 年月を渡して、その月の祝日を取得します。
+/// - `holidays_between()`: 期間を渡して、その期間の祝日を取得します。
+/// - `next_holiday()`: `chrono::NaiveDate` を渡して、その日より後で最も近い祝日を取得します。
+/// - `previous_holiday()`: `chrono::NaiveDate` を渡して、その日より前で最も近い祝日を取得します。
+/// - `get_holiday_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日の祝日を取得します。
+/// - `get_holiday_detail_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日の種別付きの祝日を取得します。
+/// - `is_holiday_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日が祝日かどうかを判定します。
+/// - `is_day_off_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日が休日かどうかを判定します。
+/// - `with_clock()`: 「今日」を基準にするメソッドで使用する時計を設定します。
+/// - `today()`: 設定されている時計から、日本時間の今日の日付を取得します。
+/// - `is_today_holiday()`: 日本時間の今日が祝日かどうかを判定します。
+/// - `is_today_day_off()`: 日本時間の今日が休日かどうかを判定します。
+/// - `next_holiday_from_today()`: 日本時間の今日より後で最も近い祝日を取得します。
+/// - `days_until_next_holiday()`: 日本時間の今日から次の祝日までの日数を取得します。
+/// - `coverage()`: 祝日データに含まれる年の範囲を取得します。
+/// - `next_business_day()`: 翌営業日を取得します。
+/// - `previous_business_day()`: 前営業日を取得します。
+/// - `add_business_days()`: N 営業日後 (負の場合は前) の日付を取得します。
+/// - `business_days_between()`: 期間内の営業日の日数を数えます。
+/// - `adjust()`: 営業日調整規則に従って、休日を営業日に調整します。
+/// - `business_days_of_month()`: 年月を渡して、その月の営業日をすべて取得します。
+/// - `count_business_days_in_month()`: 年月を渡して、その月の営業日の日数を数えます。
+/// - `nth_business_day_of_month()`: 年月を渡して、その月の第 N 営業日を取得します。
+/// - `last_business_day_of_month()`: 年月を渡して、その月の最終営業日を取得します。
+/// - `business_day_index_in_month()`: `chrono::NaiveDate` を渡して、その日が月の第何営業日かを取得します。
+/// - `adjusted_day_of_month()`: 年月と日を渡して、営業日調整規則に従って調整した日付を取得します。
+/// - `day_off_runs()`: 期間を渡して、その期間と重なる連休をすべて取得します。
+/// - `break_containing()`: `chrono::NaiveDate` を渡して、その日を含む連休を取得します。
+/// - `golden_week()`: 年を渡して、その年のゴールデンウィークを取得します。
+/// - `silver_week()`: 年を渡して、その年のシルバーウィークを取得します。
+/// - `bridge_days()`: 期間を渡して、休日に挟まれた営業日を取得します。
+/// - `explain()`: `chrono::NaiveDate` を渡して、その日が休日になる理由をすべて取得します。
+/// - `classify_days()`: 期間を渡して、期間内の日付を種別を判定しながら順に返すイテレーターを取得します。
+/// - `format_japanese()`: `chrono::NaiveDate` を渡して、和暦・曜日・祝日名を組み合わせた文字列 (`令和8年5月3日(日・憲法記念日)`) を取得します。
 pub struct Client {
     data: std::collections::BTreeMap<NaiveDate, String>,
+    holidays: std::collections::BTreeMap<NaiveDate, crate::holiday::Holiday>,
+    clock: std::sync::Arc<dyn crate::clock::Clock>,
 }
 
 impl Client {
@@ -128,19 +176,56 @@ impl Client {
         let shiftjis_bytes = holiday_service.fetch_shiftjis_csv_bytes().await?;
         let csv = holiday_service.parse_csv(shiftjis_bytes).await?;
         let data = holiday_service.deserialize_csv(&csv)?;
-        Ok(Self { data })
+        Ok(Self::from_data(data))
     }
 
     #[cfg(test)]
     #[doc = include_str!("../../../README.md")]
-    async fn init_stub() -> Result<Self, crate::error::Error> {
+    pub(crate) async fn init_stub() -> Result<Self, crate::error::Error> {
         let holiday_repository = std::sync::Arc::new(crate::repository::HolidayRepositoryStub);
         let holiday_service =
             std::sync::Arc::new(crate::service::HolidayService { holiday_repository });
         let shiftjis_bytes = holiday_service.fetch_shiftjis_csv_bytes().await?;
         let csv = holiday_service.parse_csv(shiftjis_bytes).await?;
         let data = holiday_service.deserialize_csv(&csv)?;
-        Ok(Self { data })
+        Ok(Self::from_data(data))
+    }
+
+    fn from_data(data: std::collections::BTreeMap<NaiveDate, String>) -> Self {
+        let holidays = crate::holiday::classify(&data);
+        Self {
+            data,
+            holidays,
+            clock: std::sync::Arc::new(crate::clock::SystemClock),
+        }
+    }
+
+    /// 「今日」を基準にするメソッドで使用する時計を設定します。既定はシステムの時計です。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, clock::FixedClock};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 7).ok_or("存在しない日付です".to_string())?;
+    ///     let client = Client::init().await?.with_clock(FixedClock::at_japan_date(date));
+    ///
+    ///     println!("今日は {}", client.today());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_clock(mut self, clock: impl crate::clock::Clock + 'static) -> Self {
+        self.clock = std::sync::Arc::new(clock);
+        self
+    }
+
+    /// 設定されている時計を取得します。
+    pub fn clock(&self) -> &dyn crate::clock::Clock {
+        self.clock.as_ref()
     }
 
     /// 現在内閣府から公開されている範囲の祝日一覧を取得します。
@@ -387,65 +472,1415 @@ impl Client {
             ))?;
         Ok(self.is_day_off(date))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// 現在内閣府から公開されている範囲の祝日一覧を、種別付きで取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::{client::Client, holiday::HolidayKind};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 振替休日のみを表示します。
+    ///     let substitutes = client
+    ///         .list_holiday_details()
+    ///         .values()
+    ///         .filter(|holiday| holiday.kind == HolidayKind::Substitute);
+    ///
+    ///     for holiday in substitutes {
+    ///         println!("{} | {:?}", holiday.date, holiday.substitute_for);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_holiday_details(
+        &self,
+    ) -> &std::collections::BTreeMap<NaiveDate, crate::holiday::Holiday> {
+        &self.holidays
+    }
 
-    #[tokio::test]
-    async fn test_get_holiday_known_date() {
-        let client = Client::init_stub().await.unwrap();
-        let holiday = client.get_holiday_ymd(1955, 1, 1).unwrap();
-        assert_eq!(holiday, Some("元日"));
+    ///　`chrono::NaiveDate` を渡して種別付きの祝日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 種別付きの祝日を取得
+    ///     let date = NaiveDate::from_ymd_opt(2024, 2, 12).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     match client.get_holiday_detail(date) {
+    ///         Some(holiday) => println!("2024年 2月 12日 は{} ({:?})", holiday.name, holiday.kind),
+    ///         None => println!("2024年 2月 12日 は祝日ではありません"),
+    ///     };
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.holidays.get(&date)
     }
 
-    #[tokio::test]
-    async fn test_get_holiday_unknown_date() {
-        let client = Client::init_stub().await.unwrap();
-        let holiday = client.get_holiday_ymd(1955, 1, 2).unwrap();
-        assert_eq!(holiday, None);
+    ///　年月日を渡して種別付きの祝日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 種別付きの祝日を取得
+    ///     match client.get_holiday_detail_ymd(2024, 2, 12)? {
+    ///         Some(holiday) => println!("2024年 2月 12日 は{} ({:?})", holiday.name, holiday.kind),
+    ///         None => println!("2024年 2月 12日 は祝日ではありません"),
+    ///     };
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_detail_ymd(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<Option<&crate::holiday::Holiday>, crate::error::Error> {
+        let date =
+            NaiveDate::from_ymd_opt(year, month, day).ok_or(crate::error::Error::InvalidDate(
+                format!("不正な日付です: {}年 {}月 {}日", year, month, day),
+            ))?;
+        Ok(self.get_holiday_detail(date))
     }
 
-    #[tokio::test]
-    async fn test_is_holiday_true() {
-        let client = Client::init_stub().await.unwrap();
-        let is_holiday = client.is_holiday_ymd(1955, 5, 5).unwrap();
-        assert!(is_holiday);
+    ///　振替休日の日付を渡して、振り替えられた元の祝日を取得します。
+    ///
+    /// 渡した日付が振替休日でない場合は `None` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2025, 5, 6).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     if let Some(original) = client.get_substituted_holiday(date) {
+    ///         println!("2025年 5月 6日 は{} ({}) の振替休日です", original.name, original.date);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_substituted_holiday(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.holidays
+            .get(&date)
+            .and_then(|holiday| holiday.substitute_for)
+            .and_then(|original| self.holidays.get(&original))
     }
 
-    #[tokio::test]
-    async fn test_is_holiday_false() {
-        let client = Client::init_stub().await.unwrap();
-        let is_holiday = client.is_holiday_ymd(1955, 5, 4).unwrap();
-        assert!(!is_holiday);
+    ///　祝日の日付を渡して、その祝日の振替休日を取得します。
+    ///
+    /// 渡した日付の祝日に振替休日がない場合は `None` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2025, 5, 4).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     if let Some(substitute) = client.get_substitute_holiday(date) {
+    ///         println!("2025年 5月 4日 の振替休日は {} です", substitute.date);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_substitute_holiday(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        // 振替休日は元の祝日より後にあり、間には祝日しか挟まらないため、連続する祝日の範囲だけを調べます。
+        self.holidays
+            .range(date..)
+            .zip(date.iter_days())
+            .skip(1)
+            .take_while(|((holiday_date, _), expected)| *holiday_date == expected)
+            .map(|((_, holiday), _)| holiday)
+            .find(|holiday| holiday.substitute_for == Some(date))
     }
 
-    #[tokio::test]
-    async fn test_invalid_date() {
-        let client = Client::init_stub().await.unwrap();
-        let result = client.get_holiday_ymd(1955, 2, 30);
-        assert!(result.is_err());
+    /// 振替休日と、振り替えられた元の祝日の組をすべて取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     for (substitute, original) in client.list_substitute_holidays() {
+    ///         println!("{} | {} の振替", substitute.date, original.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_substitute_holidays(
+        &self,
+    ) -> impl Iterator<Item = (&crate::holiday::Holiday, &crate::holiday::Holiday)> {
+        self.holidays.values().filter_map(|holiday| {
+            let original = self.holidays.get(&holiday.substitute_for?)?;
+            Some((holiday, original))
+        })
     }
 
-    #[tokio::test]
-    async fn test_is_day_off_holiday() {
-        let client = Client::init_stub().await.unwrap();
-        let is_day_off = client.is_day_off_ymd(1955, 1, 1).unwrap();
-        assert!(is_day_off);
+    ///　`chrono::NaiveDate` を渡して、表示用の祝日名を取得します。
+    ///
+    /// CSV 上で「休日」と記載されている日を、種別に応じて以下のように表示します。
+    ///
+    /// - 振替休日: `振替休日 (憲法記念日の振替)`
+    /// - 国民の休日: `国民の休日`
+    ///
+    /// それ以外の祝日は CSV に記載されている名称をそのまま返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2025, 5, 6).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     if let Some(name) = client.get_holiday_display_name(date) {
+    ///         println!("2025年 5月 6日 は{}", name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_display_name(&self, date: NaiveDate) -> Option<String> {
+        let holiday = self.holidays.get(&date)?;
+
+        let display_name = match holiday.kind {
+            crate::holiday::HolidayKind::Substitute => match self.get_substituted_holiday(date) {
+                Some(original) => format!("振替休日 ({}の振替)", original.name),
+                None => "振替休日".to_string(),
+            },
+            crate::holiday::HolidayKind::Citizens => "国民の休日".to_string(),
+            _ => holiday.name.clone(),
+        };
+
+        Some(display_name)
     }
 
-    #[tokio::test]
-    async fn test_is_day_off_weekend() {
-        let client = Client::init_stub().await.unwrap();
-        let is_day_off = client.is_day_off_ymd(1955, 1, 8).unwrap();
-        assert!(is_day_off);
+    ///　`chrono::NaiveDate` と表示言語を渡して祝日名を取得します。
+    ///
+    /// 対応していない祝日名の場合は、CSV に記載されている名称をそのまま返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, locale::Locale};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2025, 7, 21).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     match client.get_holiday_localized(date, Locale::English) {
+    ///         Some(holiday) => println!("2025-07-21 is {}", holiday),
+    ///         None => println!("2025-07-21 is not a holiday"),
+    ///     };
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_localized(
+        &self,
+        date: NaiveDate,
+        locale: crate::locale::Locale,
+    ) -> Option<&str> {
+        self.holidays
+            .get(&date)
+            .map(|holiday| holiday.localized_name(locale))
     }
 
-    #[tokio::test]
-    async fn test_is_day_off_weekday_non_holiday() {
-        let client = Client::init_stub().await.unwrap();
-        let is_day_off = client.is_day_off_ymd(1955, 1, 5).unwrap();
-        assert!(!is_day_off);
+    ///　年月日と表示言語を渡して祝日名を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::{client::Client, locale::Locale};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     match client.get_holiday_localized_ymd(2025, 7, 21, Locale::Romaji)? {
+    ///         Some(holiday) => println!("2025-07-21 is {}", holiday),
+    ///         None => println!("2025-07-21 is not a holiday"),
+    ///     };
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_localized_ymd(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        locale: crate::locale::Locale,
+    ) -> Result<Option<&str>, crate::error::Error> {
+        let date =
+            NaiveDate::from_ymd_opt(year, month, day).ok_or(crate::error::Error::InvalidDate(
+                format!("不正な日付です: {}年 {}月 {}日", year, month, day),
+            ))?;
+        Ok(self.get_holiday_localized(date, locale))
+    }
+
+    ///　`chrono::NaiveDate` を渡して、名称の変更をまたいで祝日を識別するための ID を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, holiday::HolidayId};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2019, 10, 14).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     // 2019年の「体育の日」は HolidayId::SportsDay です。
+    ///     assert_eq!(client.get_holiday_id(date), Some(HolidayId::SportsDay));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_id(&self, date: NaiveDate) -> Option<crate::holiday::HolidayId> {
+        self.holidays.get(&date).and_then(|holiday| holiday.id())
+    }
+
+    /// `HolidayId` を渡して、名称の変更や日付の移動をまたいでその祝日をすべて取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::{client::Client, holiday::HolidayId};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 「体育の日」と「スポーツの日」をまとめて取得します。
+    ///     for holiday in client.list_holidays_by_id(HolidayId::SportsDay) {
+    ///         println!("{} | {}", holiday.date, holiday.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_holidays_by_id(
+        &self,
+        id: crate::holiday::HolidayId,
+    ) -> impl Iterator<Item = &crate::holiday::Holiday> {
+        self.holidays
+            .values()
+            .filter(move |holiday| holiday.id() == Some(id))
+    }
+
+    /// 年を渡して、その年の祝日を日付順に取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     for holiday in client.holidays_in_year(2026)? {
+    ///         println!("{} | {}", holiday.date, holiday.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn holidays_in_year(
+        &self,
+        year: i32,
+    ) -> Result<impl DoubleEndedIterator<Item = &crate::holiday::Holiday>, crate::error::Error>
+    {
+        let (first, _) = crate::business::month_bounds(year, 1)?;
+        let (_, last) = crate::business::month_bounds(year, 12)?;
+        Ok(self.holidays_between(first..=last))
+    }
+
+    /// 年月を渡して、その月の祝日を日付順に取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     for holiday in client.holidays_in_month(2026, 5)? {
+    ///         println!("{} | {}", holiday.date, holiday.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn holidays_in_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<impl DoubleEndedIterator<Item = &crate::holiday::Holiday>, crate::error::Error>
+    {
+        let (first, last) = crate::business::month_bounds(year, month)?;
+        Ok(self.holidays_between(first..=last))
+    }
+
+    /// 期間を渡して、その期間の祝日を日付順に取得します。
+    ///
+    /// `start..end` は終了日を含まず、`start..=end` は終了日を含みます。`start..` や `..end` も指定できます。
+    /// 開始日が終了日より後の場合は何も返しません。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 4, 29).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 5, 6).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     for holiday in client.holidays_between(start..=end) {
+    ///         println!("{} | {}", holiday.date, holiday.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn holidays_between(
+        &self,
+        range: impl std::ops::RangeBounds<NaiveDate>,
+    ) -> impl DoubleEndedIterator<Item = &crate::holiday::Holiday> {
+        use std::ops::Bound;
+
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        // `BTreeMap::range()` は開始が終了より後の場合に panic するため、空として扱います。
+        let is_empty = match bounds {
+            (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) => start > end,
+            _ => false,
+        };
+
+        (!is_empty)
+            .then(|| self.holidays.range(bounds))
+            .into_iter()
+            .flatten()
+            .map(|(_, holiday)| holiday)
+    }
+
+    /// `chrono::NaiveDate` を渡して、その日より後で最も近い祝日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    ///     if let Some(holiday) = client.next_holiday(date) {
+    ///         println!("次の祝日は {} の{}です", holiday.date, holiday.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn next_holiday(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.holidays_between((std::ops::Bound::Excluded(date), std::ops::Bound::Unbounded))
+            .next()
+    }
+
+    /// `chrono::NaiveDate` を渡して、その日より前で最も近い祝日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    ///     if let Some(holiday) = client.previous_holiday(date) {
+    ///         println!("前の祝日は {} の{}です", holiday.date, holiday.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn previous_holiday(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.holidays_between(..date).next_back()
+    }
+
+    ///　任意のタイムゾーンの時刻を渡して、日本時間のその日の祝日を取得します。
+    ///
+    /// 時刻は `crate::jst::to_japan_date()` で日本時間の日付に変換してから判定します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 2026年5月3日 15:30 (UTC) は日本時間の5月4日 0:30
+    ///     let instant = Utc
+    ///         .with_ymd_and_hms(2026, 5, 3, 15, 30, 0)
+    ///         .single()
+    ///         .ok_or("存在しない時刻です".to_string())?;
+    ///
+    ///     match client.get_holiday_at(&instant) {
+    ///         Some(holiday) => println!("日本時間では{}です", holiday),
+    ///         None => println!("日本時間では祝日ではありません"),
+    ///     };
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_at<Tz: chrono::TimeZone>(
+        &self,
+        instant: &chrono::DateTime<Tz>,
+    ) -> Option<&str> {
+        self.get_holiday(crate::jst::to_japan_date(instant))
+    }
+
+    ///　任意のタイムゾーンの時刻を渡して、日本時間のその日の種別付きの祝日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 2026年5月5日 15:30 (UTC) は日本時間の5月6日 0:30
+    ///     let instant = Utc
+    ///         .with_ymd_and_hms(2026, 5, 5, 15, 30, 0)
+    ///         .single()
+    ///         .ok_or("存在しない時刻です".to_string())?;
+    ///
+    ///     if let Some(holiday) = client.get_holiday_detail_at(&instant) {
+    ///         println!("日本時間では{} ({:?}) です", holiday.name, holiday.kind);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_holiday_detail_at<Tz: chrono::TimeZone>(
+        &self,
+        instant: &chrono::DateTime<Tz>,
+    ) -> Option<&crate::holiday::Holiday> {
+        self.get_holiday_detail(crate::jst::to_japan_date(instant))
+    }
+
+    ///　任意のタイムゾーンの時刻を渡して、日本時間のその日が祝日かどうか確認します。
+    pub fn is_holiday_at<Tz: chrono::TimeZone>(&self, instant: &chrono::DateTime<Tz>) -> bool {
+        self.is_holiday(crate::jst::to_japan_date(instant))
+    }
+
+    ///　任意のタイムゾーンの時刻を渡して、日本時間のその日が**休日**(祝日+土日)かどうか確認します。
+    pub fn is_day_off_at<Tz: chrono::TimeZone>(&self, instant: &chrono::DateTime<Tz>) -> bool {
+        self.is_day_off(crate::jst::to_japan_date(instant))
+    }
+
+    /// 設定されている時計から、日本時間の今日の日付を取得します。
+    pub fn today(&self) -> NaiveDate {
+        self.clock.today()
+    }
+
+    /// 日本時間の今日が祝日かどうか確認します。今日は設定されている時計から求めます。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     if client.is_today_holiday() {
+    ///         println!("今日は祝日です");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_today_holiday(&self) -> bool {
+        self.is_holiday(self.today())
+    }
+
+    /// 日本時間の今日が**休日**(祝日+土日)かどうか確認します。今日は設定されている時計から求めます。
+    pub fn is_today_day_off(&self) -> bool {
+        self.is_day_off(self.today())
+    }
+
+    /// 日本時間の今日より後で最も近い祝日を取得します。今日は設定されている時計から求めます。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     if let Some(holiday) = client.next_holiday_from_today() {
+    ///         println!("次の祝日は {} の{}です", holiday.date, holiday.name);
+    ///     }
+    ///     if let Some(days) = client.days_until_next_holiday() {
+    ///         println!("次の祝日まであと {} 日です", days);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn next_holiday_from_today(&self) -> Option<&crate::holiday::Holiday> {
+        self.next_holiday(self.today())
+    }
+
+    /// 日本時間の今日から次の祝日までの日数を取得します。公開されている祝日がもうない場合は `None` を返します。
+    pub fn days_until_next_holiday(&self) -> Option<i64> {
+        let today = self.today();
+        self.next_holiday(today)
+            .map(|holiday| (holiday.date - today).num_days())
+    }
+
+    /// 祝日データに含まれる年の範囲を取得します。
+    ///
+    /// 最初の祝日の年の1月1日から、最後の祝日の年の12月31日までを返します。
+    /// 営業日の計算はこの範囲の中でのみ行えます。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let coverage = client.coverage();
+    ///     println!("{} 〜 {}", coverage.start(), coverage.end());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        let first = self
+            .data
+            .keys()
+            .next()
+            .and_then(|date| NaiveDate::from_ymd_opt(date.year(), 1, 1));
+        let last = self
+            .data
+            .keys()
+            .next_back()
+            .and_then(|date| NaiveDate::from_ymd_opt(date.year(), 12, 31));
+
+        match (first, last) {
+            (Some(first), Some(last)) => first..=last,
+            // 祝日データが空の場合は空の範囲を返します。
+            _ => NaiveDate::MAX..=NaiveDate::MIN,
+        }
+    }
+
+    ///　`chrono::NaiveDate` を渡して、その日より後で最も近い営業日 (土日祝日以外の日) を取得します。
+    ///
+    /// 祝日データの範囲外に達した場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 2).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 5月 2日 の翌営業日は {}", client.next_business_day(date)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn next_business_day(&self, date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        crate::business::BusinessCalendar::next_business_day(self, date)
+    }
+
+    ///　`chrono::NaiveDate` を渡して、その日より前で最も近い営業日 (土日祝日以外の日) を取得します。
+    ///
+    /// 祝日データの範囲外に達した場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 7).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 5月 7日 の前営業日は {}", client.previous_business_day(date)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn previous_business_day(&self, date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        crate::business::BusinessCalendar::previous_business_day(self, date)
+    }
+
+    ///　`chrono::NaiveDate` を渡して、`days` 営業日後の日付を取得します。`days` が負の場合は前にさかのぼります。
+    ///
+    /// `date` 自身は数えません。`days` が `0` の場合は `date` をそのまま返します。
+    /// 祝日データの範囲外に達した場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 4, 28).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 4月 28日 の5営業日後は {}", client.add_business_days(date, 5)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_business_days(
+        &self,
+        date: NaiveDate,
+        days: i64,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        crate::business::BusinessCalendar::add_business_days(self, date, days)
+    }
+
+    ///　`start` 以上 `end` 未満の期間に含まれる営業日 (土日祝日以外の日) の日数を数えます。
+    ///
+    /// `end` が `start` より前の場合は負の値を返します。
+    /// 期間が祝日データの範囲外にかかる場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 5月 の営業日は {} 日です", client.business_days_between(start, end)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn business_days_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<i64, crate::error::Error> {
+        crate::business::BusinessCalendar::business_days_between(self, start, end)
+    }
+
+    ///　`chrono::NaiveDate` と営業日調整規則を渡して、休日を営業日 (土日祝日以外の日) に調整します。
+    ///
+    /// 祝日データの範囲外に達した場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, convention::BusinessDayConvention};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 3).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 5月 3日 の翌営業日調整後は {}", client.adjust(date, BusinessDayConvention::Following)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn adjust(
+        &self,
+        date: NaiveDate,
+        convention: crate::convention::BusinessDayConvention,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        crate::business::BusinessCalendar::adjust(self, date, convention)
+    }
+
+    ///　年月を渡して、その月の営業日 (土日祝日以外の日) をすべて取得します。
+    ///
+    /// 月の一部でも祝日データの範囲外の場合は `crate::error::Error::OutOfRange` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     for date in client.business_days_of_month(2026, 5)? {
+    ///         println!("{}", date);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn business_days_of_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<NaiveDate>, crate::error::Error> {
+        crate::business::BusinessCalendar::business_days_of_month(self, year, month)
+    }
+
+    ///　年月を渡して、その月の営業日 (土日祝日以外の日) の日数を数えます。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     println!("2026年 5月 の営業日は {} 日です", client.count_business_days_in_month(2026, 5)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn count_business_days_in_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<i64, crate::error::Error> {
+        crate::business::BusinessCalendar::count_business_days_in_month(self, year, month)
+    }
+
+    ///　年月を渡して、その月の第 `nth` 営業日を取得します。`nth` が負の場合は月末から数えます。
+    ///
+    /// 該当する営業日がない場合は `None` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     if let Some(date) = client.nth_business_day_of_month(2026, 5, 5)? {
+    ///         println!("2026年 5月 の第5営業日は {}", date);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn nth_business_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+        nth: i32,
+    ) -> Result<Option<NaiveDate>, crate::error::Error> {
+        crate::business::BusinessCalendar::nth_business_day_of_month(self, year, month, nth)
+    }
+
+    ///　年月を渡して、その月の最終営業日を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     if let Some(date) = client.last_business_day_of_month(2026, 5)? {
+    ///         println!("2026年 5月 の最終営業日は {}", date);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn last_business_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Option<NaiveDate>, crate::error::Error> {
+        crate::business::BusinessCalendar::last_business_day_of_month(self, year, month)
+    }
+
+    ///　`chrono::NaiveDate` を渡して、その日が月の第何営業日かを取得します。休日の場合は `None` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 7).ok_or("存在しない日付です".to_string())?;
+    ///     if let Some(index) = client.business_day_index_in_month(date)? {
+    ///         println!("2026年 5月 7日 は第{}営業日です", index);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn business_day_index_in_month(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<u32>, crate::error::Error> {
+        crate::business::BusinessCalendar::business_day_index_in_month(self, date)
+    }
+
+    ///　年月と日を渡して、その日を営業日調整規則に従って調整します。
+    ///
+    /// `day` が月の日数より大きい場合は月末日を使用します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::{client::Client, convention::BusinessDayConvention};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     // 毎月25日、休日の場合は前営業日
+    ///     let date = client.adjusted_day_of_month(2026, 10, 25, BusinessDayConvention::Preceding)?;
+    ///     println!("2026年 10月 の支払日は {}", date);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn adjusted_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        convention: crate::convention::BusinessDayConvention,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        crate::business::BusinessCalendar::adjusted_day_of_month(self, year, month, day, convention)
+    }
+
+    ///　期間を渡して、その期間と重なる連休 (土日祝日が連続する期間) を日付順にすべて取得します。
+    ///
+    /// 期間の端にかかる連休は、期間の外側も含めた連休全体を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 1, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     for run in client.day_off_runs(start..=end)?.iter().filter(|run| run.days() >= 3) {
+    ///         println!("{} 〜 {} ({} 連休)", run.start, run.end, run.days());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn day_off_runs(
+        &self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+    ) -> Result<Vec<crate::runs::DayOffRun>, crate::error::Error> {
+        crate::business::BusinessCalendar::day_off_runs(self, period)
+    }
+
+    ///　`chrono::NaiveDate` を渡して、その日を含む連休を取得します。営業日の場合は `None` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 4).ok_or("存在しない日付です".to_string())?;
+    ///     if let Some(run) = client.break_containing(date)? {
+    ///         println!("2026年 5月 4日 は {} 連休の{}日目です", run.days(), (date - run.start).num_days() + 1);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn break_containing(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<crate::runs::DayOffRun>, crate::error::Error> {
+        crate::business::BusinessCalendar::break_containing(self, date)
+    }
+
+    ///　年を渡して、その年のゴールデンウィーク (5月3日を含む3日以上の連休) を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     if let Some(run) = client.golden_week(2026)? {
+    ///         println!("2026年のゴールデンウィークは {} 〜 {} ({} 連休)", run.start, run.end, run.days());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn golden_week(
+        &self,
+        year: i32,
+    ) -> Result<Option<crate::runs::DayOffRun>, crate::error::Error> {
+        crate::business::BusinessCalendar::golden_week(self, year)
+    }
+
+    ///　年を渡して、その年のシルバーウィーク (敬老の日と秋分の日をともに含む連休) を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     match client.silver_week(2026)? {
+    ///         Some(run) => println!("2026年のシルバーウィークは {} 〜 {} ({} 連休)", run.start, run.end, run.days()),
+    ///         None => println!("2026年はシルバーウィークがありません"),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn silver_week(
+        &self,
+        year: i32,
+    ) -> Result<Option<crate::runs::DayOffRun>, crate::error::Error> {
+        crate::business::BusinessCalendar::silver_week(self, year)
+    }
+
+    ///　期間を渡して、連休の間にある `max_gap` 日以下の営業日 (休日に挟まれた平日) を取得します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 1, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 12, 31).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     for bridge in client.bridge_days(start..=end, 1)? {
+    ///         println!("{:?} を休むと {} 連休", bridge.days, bridge.combined_len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn bridge_days(
+        &self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+        max_gap: usize,
+    ) -> Result<Vec<crate::bridge::Bridge>, crate::error::Error> {
+        crate::business::BusinessCalendar::bridge_days(self, period, max_gap)
+    }
+
+    ///　`chrono::NaiveDate` を渡して、その日が休日になる理由 (週末・祝日・振替休日) をすべて取得します。営業日の場合は空です。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 6).ok_or("存在しない日付です".to_string())?;
+    ///     for reason in client.explain(date) {
+    ///         println!("{} ({})", reason.description(), reason.source().description());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        crate::business::BusinessCalendar::explain(self, date)
+    }
+
+    ///　期間を渡して、期間内の日付を種別 (平日・土曜日・日曜日・国民の祝日・振替休日・国民の休日) を判定しながら順に返すイテレーターを取得します。
+    ///
+    /// `business_days()` や `days_off()` で営業日・休日に絞り込めます。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let start = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("存在しない日付です".to_string())?;
+    ///     let end = NaiveDate::from_ymd_opt(2026, 5, 7).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     for day in client.classify_days(start..=end)? {
+    ///         println!("{} | {:?}", day.date, day.kind);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn classify_days(
+        &self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+    ) -> Result<crate::days::Days<'_, Self>, crate::error::Error> {
+        crate::business::BusinessCalendar::classify_days(self, period)
+    }
+
+    ///　`chrono::NaiveDate` を渡して、和暦・曜日・祝日名を組み合わせた文字列を取得します。
+    ///
+    /// `令和8年5月3日(日・憲法記念日)` の形式です。祝日でない日は `令和8年5月7日(木)` の形式になります。
+    /// 振替休日は `振替休日`、国民の休日は `国民の休日` と表示します。
+    /// 明治より前の日付の場合は `crate::error::Error::InvalidDate` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 3).ok_or("存在しない日付です".to_string())?;
+    ///     println!("{}", client.format_japanese(date)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn format_japanese(&self, date: NaiveDate) -> Result<String, crate::error::Error> {
+        let era_date = crate::era::EraDate::from_date(date)?;
+        let weekday = crate::explain::weekday_name(date.weekday());
+
+        let holiday_name = self.holidays.get(&date).map(|holiday| match holiday.kind {
+            crate::holiday::HolidayKind::Substitute => "振替休日",
+            crate::holiday::HolidayKind::Citizens => "国民の休日",
+            _ => holiday.name.as_str(),
+        });
+
+        Ok(match holiday_name {
+            Some(name) => format!("{}({}・{})", era_date.format(), weekday, name),
+            None => format!("{}({})", era_date.format(), weekday),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_holiday_known_date() {
+        let client = Client::init_stub().await.unwrap();
+        let holiday = client.get_holiday_ymd(1955, 1, 1).unwrap();
+        assert_eq!(holiday, Some("元日"));
+    }
+
+    #[tokio::test]
+    async fn test_get_holiday_unknown_date() {
+        let client = Client::init_stub().await.unwrap();
+        let holiday = client.get_holiday_ymd(1955, 1, 2).unwrap();
+        assert_eq!(holiday, None);
+    }
+
+    #[tokio::test]
+    async fn test_is_holiday_true() {
+        let client = Client::init_stub().await.unwrap();
+        let is_holiday = client.is_holiday_ymd(1955, 5, 5).unwrap();
+        assert!(is_holiday);
+    }
+
+    #[tokio::test]
+    async fn test_is_holiday_false() {
+        let client = Client::init_stub().await.unwrap();
+        let is_holiday = client.is_holiday_ymd(1955, 5, 4).unwrap();
+        assert!(!is_holiday);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_date() {
+        let client = Client::init_stub().await.unwrap();
+        let result = client.get_holiday_ymd(1955, 2, 30);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_is_day_off_holiday() {
+        let client = Client::init_stub().await.unwrap();
+        let is_day_off = client.is_day_off_ymd(1955, 1, 1).unwrap();
+        assert!(is_day_off);
+    }
+
+    #[tokio::test]
+    async fn test_is_day_off_weekend() {
+        let client = Client::init_stub().await.unwrap();
+        let is_day_off = client.is_day_off_ymd(1955, 1, 8).unwrap();
+        assert!(is_day_off);
+    }
+
+    #[tokio::test]
+    async fn test_is_day_off_weekday_non_holiday() {
+        let client = Client::init_stub().await.unwrap();
+        let is_day_off = client.is_day_off_ymd(1955, 1, 5).unwrap();
+        assert!(!is_day_off);
+    }
+
+    #[tokio::test]
+    async fn test_get_holiday_detail_substitute() {
+        let client = Client::init_stub().await.unwrap();
+        let holiday = client.get_holiday_detail_ymd(2024, 2, 12).unwrap().unwrap();
+        assert_eq!(holiday.kind, crate::holiday::HolidayKind::Substitute);
+        assert_eq!(holiday.substitute_for, NaiveDate::from_ymd_opt(2024, 2, 11));
+    }
+
+    #[tokio::test]
+    async fn test_get_holiday_detail_national() {
+        let client = Client::init_stub().await.unwrap();
+        let holiday = client.get_holiday_detail_ymd(1955, 1, 1).unwrap().unwrap();
+        assert_eq!(holiday.name, "元日");
+        assert_eq!(holiday.kind, crate::holiday::HolidayKind::National);
+    }
+
+    #[tokio::test]
+    async fn test_get_substituted_holiday() {
+        let client = Client::init_stub().await.unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 5, 6).unwrap();
+        let original = client.get_substituted_holiday(date).unwrap();
+        assert_eq!(original.name, "みどりの日");
+    }
+
+    #[tokio::test]
+    async fn test_get_substitute_holiday() {
+        let client = Client::init_stub().await.unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 5, 3).unwrap();
+        let substitute = client.get_substitute_holiday(date).unwrap();
+        assert_eq!(
+            substitute.date,
+            NaiveDate::from_ymd_opt(2026, 5, 6).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_holiday_display_name() {
+        let client = Client::init_stub().await.unwrap();
+        let date = NaiveDate::from_ymd_opt(2019, 5, 6).unwrap();
+        assert_eq!(
+            client.get_holiday_display_name(date),
+            Some("振替休日 (こどもの日の振替)".to_string())
+        );
+        let date = NaiveDate::from_ymd_opt(2026, 9, 22).unwrap();
+        assert_eq!(
+            client.get_holiday_display_name(date),
+            Some("国民の休日".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_holiday_localized() {
+        let client = Client::init_stub().await.unwrap();
+        let holiday = client
+            .get_holiday_localized_ymd(2025, 7, 21, crate::locale::Locale::English)
+            .unwrap();
+        assert_eq!(holiday, Some("Marine Day"));
+        let holiday = client
+            .get_holiday_localized_ymd(2025, 7, 21, crate::locale::Locale::Japanese)
+            .unwrap();
+        assert_eq!(holiday, Some("海の日"));
+    }
+
+    #[tokio::test]
+    async fn test_list_holidays_by_id() {
+        let client = Client::init_stub().await.unwrap();
+        let names: std::collections::BTreeSet<&str> = client
+            .list_holidays_by_id(crate::holiday::HolidayId::SportsDay)
+            .map(|holiday| holiday.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            std::collections::BTreeSet::from(["体育の日", "スポーツの日"])
+        );
+
+        let emperors_birthdays: Vec<NaiveDate> = client
+            .list_holidays_by_id(crate::holiday::HolidayId::EmperorsBirthday)
+            .map(|holiday| holiday.date)
+            .filter(|date| date.year() >= 2019)
+            .collect();
+        assert_eq!(
+            emperors_birthdays.first(),
+            NaiveDate::from_ymd_opt(2020, 2, 23).as_ref()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_holidays_between() {
+        let client = Client::init_stub().await.unwrap();
+        let start = NaiveDate::from_ymd_opt(2026, 5, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 5, 6).unwrap();
+
+        assert_eq!(client.holidays_between(start..=end).count(), 4);
+        assert_eq!(client.holidays_between(start..end).count(), 3);
+        assert_eq!(client.holidays_between(end..start).count(), 0);
+        assert_eq!(client.holidays_in_month(2026, 5).unwrap().count(), 4);
+        assert!(client.holidays_in_month(2026, 13).is_err());
+        // 2026年は16の祝日と、振替休日 (5月6日) と国民の休日 (9月22日)
+        assert_eq!(client.holidays_in_year(2026).unwrap().count(), 18);
+    }
+
+    #[tokio::test]
+    async fn test_next_and_previous_holiday() {
+        let client = Client::init_stub().await.unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 5, 3).unwrap();
+
+        assert_eq!(
+            client.next_holiday(date).unwrap().date,
+            NaiveDate::from_ymd_opt(2026, 5, 4).unwrap()
+        );
+        assert_eq!(
+            client.previous_holiday(date).unwrap().date,
+            NaiveDate::from_ymd_opt(2026, 4, 29).unwrap()
+        );
+        assert!(
+            client
+                .next_holiday(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap())
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lookups_at_instant() {
+        use chrono::TimeZone;
+
+        let client = Client::init_stub().await.unwrap();
+
+        // 2026年5月4日 0:30 (日本時間) は UTC では5月3日 15:30
+        let instant = chrono::Utc.with_ymd_and_hms(2026, 5, 3, 15, 30, 0).unwrap();
+        assert_eq!(client.get_holiday_at(&instant), Some("みどりの日"));
+        assert_eq!(
+            client.get_holiday_detail_at(&instant).unwrap().date,
+            NaiveDate::from_ymd_opt(2026, 5, 4).unwrap()
+        );
+
+        // 2026年5月7日 8:59 (日本時間) は平日
+        let instant = chrono::Utc.with_ymd_and_hms(2026, 5, 6, 23, 59, 0).unwrap();
+        assert!(!client.is_holiday_at(&instant));
+        assert!(!client.is_day_off_at(&instant));
     }
 }
@@ -0,0 +1,132 @@
+use chrono::{NaiveDate, TimeZone};
+
+/// 現在時刻を提供する時計です。
+///
+/// `crate::client::Client` の「今日」を基準にするメソッドは、この時計から日本時間の今日の日付を求めます。
+/// 既定では `SystemClock` を使用します。テストでは `FixedClock` を設定すると結果を固定できます。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use jp_holidays_lib::{client::Client, clock::FixedClock};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let now = Utc
+///         .with_ymd_and_hms(2026, 5, 3, 15, 30, 0)
+///         .single()
+///         .ok_or("存在しない時刻です".to_string())?;
+///     let client = Client::init().await?.with_clock(FixedClock::new(now));
+///
+///     // 日本時間では2026年5月4日 (みどりの日)
+///     println!("今日は{}", if client.is_today_holiday() { "祝日です" } else { "祝日ではありません" });
+///
+///     Ok(())
+/// }
+/// ```
+pub trait Clock: Send + Sync {
+    /// 現在時刻を取得します。
+    fn now(&self) -> chrono::DateTime<chrono::Utc>;
+
+    /// 日本時間の今日の日付を取得します。
+    fn today(&self) -> NaiveDate {
+        crate::jst::to_japan_date(&self.now())
+    }
+}
+
+/// システムの時計です。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc::now()
+    }
+}
+
+/// 常に同じ時刻を返す時計です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    now: chrono::DateTime<chrono::Utc>,
+}
+
+impl FixedClock {
+    /// 任意のタイムゾーンの時刻を渡して初期化します。
+    pub fn new<Tz: TimeZone>(now: chrono::DateTime<Tz>) -> Self {
+        Self {
+            now: now.with_timezone(&chrono::Utc),
+        }
+    }
+
+    /// 日本時間の日付を渡して、その日の 0:00 (日本時間) を返す時計を作成します。
+    pub fn at_japan_date(date: NaiveDate) -> Self {
+        let midnight = crate::jst::offset()
+            .from_local_datetime(&date.and_time(chrono::NaiveTime::MIN))
+            .single()
+            .expect("固定オフセットの時刻は一意に定まります");
+        Self::new(midnight)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        self.now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_today_relative_lookups() {
+        // 2026年5月4日 0:30 (日本時間)
+        let now = chrono::Utc.with_ymd_and_hms(2026, 5, 3, 15, 30, 0).unwrap();
+        let client = crate::client::Client::init_stub()
+            .await
+            .unwrap()
+            .with_clock(FixedClock::new(now));
+
+        assert_eq!(client.today(), ymd(2026, 5, 4));
+        assert!(client.is_today_holiday());
+        assert!(client.is_today_day_off());
+        assert_eq!(
+            client.next_holiday_from_today().unwrap().date,
+            ymd(2026, 5, 5)
+        );
+        assert_eq!(client.days_until_next_holiday(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_days_until_next_holiday() {
+        let client = crate::client::Client::init_stub()
+            .await
+            .unwrap()
+            .with_clock(FixedClock::at_japan_date(ymd(2026, 5, 7)));
+
+        assert!(!client.is_today_holiday());
+        assert!(!client.is_today_day_off());
+        // 次の祝日は海の日 (7月20日)
+        assert_eq!(client.next_holiday_from_today().unwrap().name, "海の日");
+        assert_eq!(client.days_until_next_holiday(), Some(74));
+
+        // 祝日データの最後の祝日より後
+        let client = client.with_clock(FixedClock::at_japan_date(ymd(2026, 12, 1)));
+        assert_eq!(client.days_until_next_holiday(), None);
+    }
+
+    #[test]
+    fn test_today_is_japan_date() {
+        // 2026年12月31日 15:00 (UTC) は日本時間の2027年1月1日 0:00
+        let now = chrono::Utc
+            .with_ymd_and_hms(2026, 12, 31, 15, 0, 0)
+            .unwrap();
+        assert_eq!(FixedClock::new(now).today(), ymd(2027, 1, 1));
+        assert_eq!(FixedClock::at_japan_date(ymd(2027, 1, 1)).now(), now);
+    }
+}
@@ -0,0 +1,600 @@
+use chrono::NaiveDate;
+
+/// 設定ファイルで記述する会社カレンダーの定義です。
+///
+/// TOML・YAML・JSON のいずれかで記述し、`build()` で `Client` の祝日データに重ねたカレンダーを作成します。
+/// 日付は `"2026-06-01"`、月日は `"12-29"` の形式の文字列で、曜日は `"sat"` や `"土"` のように記述します。
+///
+/// ```toml
+/// # 土日を休日とします (省略した場合も土日です)
+/// [work_week]
+/// days_off = ["sat", "sun"]
+///
+/// # 期間を指定して休日の曜日を変更します
+/// [[work_week_periods]]
+/// start = "2026-10-01"
+/// end = "2026-12-31"
+/// days_off = ["sun"]
+///
+/// # 一度限りの休業日
+/// [[closures]]
+/// date = "2026-06-01"
+/// label = "創立記念日"
+///
+/// [[closures]]
+/// start = "2026-08-13"
+/// end = "2026-08-16"
+/// label = "お盆休み"
+///
+/// # 毎年繰り返す休業日
+/// [[rules]]
+/// label = "年末年始"
+/// span = { start = "12-29", end = "01-03" }
+///
+/// [[rules]]
+/// label = "成人の日の翌営業日"
+/// holiday = "成人の日"
+/// steps = ["next_business_day"]
+///
+/// [[rules]]
+/// label = "社内運動会"
+/// nth_weekday = { month = 11, nth = 3, weekday = "fri" }
+///
+/// # 休日であっても出勤する日
+/// [[working_days]]
+/// date = "2026-06-06"
+/// label = "振替出勤日"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CalendarDefinition {
+    /// 期間の指定がない日に適用する休日の曜日
+    #[serde(default)]
+    pub work_week: Option<WorkWeekDefinition>,
+
+    /// 期間を指定して適用する休日の曜日
+    #[serde(default)]
+    pub work_week_periods: Vec<WorkWeekPeriodDefinition>,
+
+    /// 一度限りの休業日
+    #[serde(default)]
+    pub closures: Vec<ClosureDefinition>,
+
+    /// 毎年繰り返す休業日
+    #[serde(default)]
+    pub rules: Vec<RuleDefinition>,
+
+    /// 休日であっても出勤する日
+    #[serde(default)]
+    pub working_days: Vec<WorkingDayDefinition>,
+}
+
+/// 休日の曜日の定義です。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkWeekDefinition {
+    pub days_off: Vec<String>,
+}
+
+/// 期間を指定した休日の曜日の定義です。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkWeekPeriodDefinition {
+    pub start: String,
+    pub end: String,
+    pub days_off: Vec<String>,
+}
+
+/// 一度限りの休業日の定義です。`date` か、`start` と `end` のどちらかを指定します。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClosureDefinition {
+    pub date: Option<String>,
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub label: Option<String>,
+}
+
+/// 出勤日の定義です。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkingDayDefinition {
+    pub date: String,
+    pub label: Option<String>,
+}
+
+/// 毎年繰り返す休業日の定義です。
+///
+/// 基準日として `date`・`span`・`nth_weekday`・`holiday` のいずれか1つを指定します。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleDefinition {
+    pub label: Option<String>,
+    pub date: Option<String>,
+    pub span: Option<SpanDefinition>,
+    pub nth_weekday: Option<NthWeekdayDefinition>,
+    pub holiday: Option<String>,
+    #[serde(default)]
+    pub steps: Vec<StepDefinition>,
+}
+
+/// 毎年の期間の定義です。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanDefinition {
+    pub start: String,
+    pub end: String,
+}
+
+/// 毎年の第 N X曜日の定義です。
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NthWeekdayDefinition {
+    pub month: u32,
+    pub nth: i32,
+    pub weekday: String,
+}
+
+/// ルールの基準日に適用する操作の定義です。
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepDefinition {
+    Offset(i64),
+    NextBusinessDay,
+    PreviousBusinessDay,
+    OnlyOn(Vec<String>),
+}
+
+impl CalendarDefinition {
+    /// TOML の文字列から読み込みます。
+    pub fn from_toml_str(source: &str) -> Result<Self, crate::error::Error> {
+        toml::from_str(source).map_err(|e| crate::error::Error::Config(e.to_string()))
+    }
+
+    /// YAML の文字列から読み込みます。
+    ///
+    /// `steps` は他の形式と同じく `- offset: 1` のような書き方で記述します。
+    pub fn from_yaml_str(source: &str) -> Result<Self, crate::error::Error> {
+        serde_yaml_ng::with::singleton_map_recursive::deserialize(
+            serde_yaml_ng::Deserializer::from_str(source),
+        )
+        .map_err(|e| crate::error::Error::Config(e.to_string()))
+    }
+
+    /// JSON の文字列から読み込みます。
+    pub fn from_json_str(source: &str) -> Result<Self, crate::error::Error> {
+        serde_json::from_str(source).map_err(|e| crate::error::Error::Config(e.to_string()))
+    }
+
+    /// ファイルから読み込みます。形式は拡張子 (`.toml`・`.yaml`・`.yml`・`.json`) で判断します。
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self, crate::error::Error> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| {
+            crate::error::Error::Config(format!("{} を読み込めません: {}", path.display(), e))
+        })?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml_str(&source),
+            Some("yaml" | "yml") => Self::from_yaml_str(&source),
+            Some("json") => Self::from_json_str(&source),
+            _ => Err(crate::error::Error::Config(format!(
+                "{} の形式を判断できません (.toml・.yaml・.yml・.json に対応しています)",
+                path.display()
+            ))),
+        }
+    }
+
+    /// 定義を検証し、`Client` の祝日データに重ねたカレンダーを作成します。
+    ///
+    /// 定義に誤りがある場合は、該当する項目を示した `crate::error::Error::Config` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{business::BusinessCalendar, client::Client, config::CalendarDefinition};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let definition = CalendarDefinition::from_toml_str(
+    ///         r#"
+    ///         [[closures]]
+    ///         date = "2026-06-01"
+    ///         label = "創立記念日"
+    ///
+    ///         [[rules]]
+    ///         label = "年末年始"
+    ///         span = { start = "12-29", end = "01-03" }
+    ///         "#,
+    ///     )?;
+    ///     let calendar = definition.build(&client)?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 6月 1日 は{}", if calendar.is_day_off(date) { "休日です" } else { "営業日です" });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn build<'a>(
+        &self,
+        client: &'a crate::client::Client,
+    ) -> Result<crate::overlay::OverlayCalendar<crate::calendar::Calendar<'a>>, crate::error::Error>
+    {
+        let mut calendar = crate::calendar::Calendar::new(client);
+
+        if let Some(work_week) = &self.work_week {
+            calendar = calendar.with_work_week(parse_work_week(&work_week.days_off, "work_week")?);
+        }
+
+        for (index, period) in self.work_week_periods.iter().enumerate() {
+            let path = format!("work_week_periods[{}]", index);
+            let start = parse_date(&period.start, &format!("{}.start", path))?;
+            let end = parse_date(&period.end, &format!("{}.end", path))?;
+            ensure_ordered(start, end, &path)?;
+            let work_week = parse_work_week(&period.days_off, &format!("{}.days_off", path))?;
+            calendar = calendar.with_work_week_between(start..=end, work_week);
+        }
+
+        let mut overlay = crate::overlay::OverlayCalendar::new(calendar);
+
+        for (index, closure) in self.closures.iter().enumerate() {
+            let path = format!("closures[{}]", index);
+            let label = closure
+                .label
+                .clone()
+                .unwrap_or_else(|| "休業日".to_string());
+
+            overlay = match (&closure.date, &closure.start, &closure.end) {
+                (Some(date), None, None) => {
+                    overlay.with_closure(parse_date(date, &format!("{}.date", path))?, label)
+                }
+                (None, Some(start), Some(end)) => {
+                    let start = parse_date(start, &format!("{}.start", path))?;
+                    let end = parse_date(end, &format!("{}.end", path))?;
+                    ensure_ordered(start, end, &path)?;
+                    overlay.with_closures_between(start..=end, label)
+                }
+                _ => {
+                    return Err(invalid(
+                        &path,
+                        "date か、start と end のどちらかを指定してください",
+                    ));
+                }
+            };
+        }
+
+        for (index, definition) in self.rules.iter().enumerate() {
+            let path = format!("rules[{}]", index);
+            let rule = parse_rule(definition, &path)?;
+            overlay = overlay
+                .with_rule(&rule)
+                .map_err(|e| invalid(&path, &e.to_string()))?;
+        }
+
+        for (index, working_day) in self.working_days.iter().enumerate() {
+            let path = format!("working_days[{}]", index);
+            let date = parse_date(&working_day.date, &format!("{}.date", path))?;
+            let label = working_day
+                .label
+                .clone()
+                .unwrap_or_else(|| "出勤日".to_string());
+            overlay = overlay.with_working_day(date, label);
+        }
+
+        Ok(overlay)
+    }
+}
+
+/// ファイルから会社カレンダーの定義を読み込み、`Client` の祝日データに重ねたカレンダーを作成します。
+///
+/// ## 使用例
+///
+/// ```no_run
+/// use jp_holidays_lib::{client::Client, config::load_calendar};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///     let calendar = load_calendar(&client, "calendar.toml")?;
+///
+///     for (date, entry) in calendar.list_overrides() {
+///         println!("{} | {}", date, entry.label());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn load_calendar<'a>(
+    client: &'a crate::client::Client,
+    path: impl AsRef<std::path::Path>,
+) -> Result<crate::overlay::OverlayCalendar<crate::calendar::Calendar<'a>>, crate::error::Error> {
+    CalendarDefinition::from_path(path)?.build(client)
+}
+
+fn parse_rule(
+    definition: &RuleDefinition,
+    path: &str,
+) -> Result<crate::rule::Rule, crate::error::Error> {
+    let anchors = [
+        definition.date.is_some(),
+        definition.span.is_some(),
+        definition.nth_weekday.is_some(),
+        definition.holiday.is_some(),
+    ];
+    if anchors.iter().filter(|specified| **specified).count() != 1 {
+        return Err(invalid(
+            path,
+            "date・span・nth_weekday・holiday のいずれか1つを指定してください",
+        ));
+    }
+
+    let mut rule = if let Some(date) = &definition.date {
+        let (month, day) = parse_month_day(date, &format!("{}.date", path))?;
+        crate::rule::Rule::date(month, day)
+            .map_err(|e| invalid(&format!("{}.date", path), &e.to_string()))?
+    } else if let Some(span) = &definition.span {
+        let (start_month, start_day) =
+            parse_month_day(&span.start, &format!("{}.span.start", path))?;
+        let (end_month, end_day) = parse_month_day(&span.end, &format!("{}.span.end", path))?;
+        crate::rule::Rule::span(start_month, start_day, end_month, end_day)
+            .map_err(|e| invalid(&format!("{}.span", path), &e.to_string()))?
+    } else if let Some(nth_weekday) = &definition.nth_weekday {
+        let field = format!("{}.nth_weekday", path);
+        if !(1..=12).contains(&nth_weekday.month) {
+            return Err(invalid(
+                &format!("{}.month", field),
+                "1 から 12 で指定してください",
+            ));
+        }
+        if nth_weekday.nth == 0 || !(-5..=5).contains(&nth_weekday.nth) {
+            return Err(invalid(
+                &format!("{}.nth", field),
+                "1 から 5、または -1 から -5 で指定してください",
+            ));
+        }
+        let weekday = parse_weekday(&nth_weekday.weekday, &format!("{}.weekday", field))?;
+        crate::rule::Rule::nth_weekday(nth_weekday.month, nth_weekday.nth, weekday)
+            .map_err(|e| invalid(&field, &e.to_string()))?
+    } else if let Some(name) = &definition.holiday {
+        let id = crate::holiday::HolidayId::from_name(name).ok_or_else(|| {
+            invalid(
+                &format!("{}.holiday", path),
+                &format!("不明な祝日の名称です: {}", name),
+            )
+        })?;
+        crate::rule::Rule::holiday(id)
+    } else {
+        unreachable!("基準日は1つだけ指定されています")
+    };
+
+    for (index, step) in definition.steps.iter().enumerate() {
+        let field = format!("{}.steps[{}]", path, index);
+        rule = match step {
+            StepDefinition::Offset(days) => rule.offset(*days),
+            StepDefinition::NextBusinessDay => rule.next_business_day(),
+            StepDefinition::PreviousBusinessDay => rule.previous_business_day(),
+            StepDefinition::OnlyOn(weekdays) => rule.only_on(
+                weekdays
+                    .iter()
+                    .map(|weekday| parse_weekday(weekday, &field))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+    }
+
+    if let Some(label) = &definition.label {
+        rule = rule.with_label(label);
+    }
+
+    Ok(rule)
+}
+
+fn parse_work_week(
+    days_off: &[String],
+    path: &str,
+) -> Result<crate::calendar::WorkWeek, crate::error::Error> {
+    let weekdays = days_off
+        .iter()
+        .enumerate()
+        .map(|(index, weekday)| parse_weekday(weekday, &format!("{}[{}]", path, index)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(crate::calendar::WorkWeek::new(weekdays))
+}
+
+fn parse_weekday(value: &str, path: &str) -> Result<chrono::Weekday, crate::error::Error> {
+    let japanese = match value.trim_end_matches("曜日").trim_end_matches('曜') {
+        "月" => Some(chrono::Weekday::Mon),
+        "火" => Some(chrono::Weekday::Tue),
+        "水" => Some(chrono::Weekday::Wed),
+        "木" => Some(chrono::Weekday::Thu),
+        "金" => Some(chrono::Weekday::Fri),
+        "土" => Some(chrono::Weekday::Sat),
+        "日" => Some(chrono::Weekday::Sun),
+        _ => None,
+    };
+
+    japanese
+        .or_else(|| value.parse().ok())
+        .ok_or_else(|| invalid(path, &format!("不明な曜日です: {}", value)))
+}
+
+fn parse_date(value: &str, path: &str) -> Result<NaiveDate, crate::error::Error> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        invalid(
+            path,
+            &format!("日付は YYYY-MM-DD の形式で指定してください: {}", value),
+        )
+    })
+}
+
+fn parse_month_day(value: &str, path: &str) -> Result<(u32, u32), crate::error::Error> {
+    let error = || {
+        invalid(
+            path,
+            &format!("月日は MM-DD の形式で指定してください: {}", value),
+        )
+    };
+
+    let (month, day) = value.split_once('-').ok_or_else(error)?;
+    let month: u32 = month.parse().map_err(|_| error())?;
+    let day: u32 = day.parse().map_err(|_| error())?;
+
+    // うるう年で存在するかどうかを確認します。
+    NaiveDate::from_ymd_opt(2000, month, day).ok_or_else(error)?;
+
+    Ok((month, day))
+}
+
+fn ensure_ordered(start: NaiveDate, end: NaiveDate, path: &str) -> Result<(), crate::error::Error> {
+    if start <= end {
+        Ok(())
+    } else {
+        Err(invalid(
+            path,
+            &format!("end ({}) が start ({}) より前です", end, start),
+        ))
+    }
+}
+
+fn invalid(path: &str, message: &str) -> crate::error::Error {
+    crate::error::Error::Config(format!("{}: {}", path, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::BusinessCalendar;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    const TOML: &str = r#"
+        [work_week]
+        days_off = ["sun", "水"]
+
+        [[closures]]
+        date = "2026-06-01"
+        label = "創立記念日"
+
+        [[closures]]
+        start = "2026-08-13"
+        end = "2026-08-16"
+        label = "お盆休み"
+
+        [[rules]]
+        label = "年末年始"
+        span = { start = "12-29", end = "01-03" }
+
+        [[rules]]
+        label = "成人の日の翌営業日"
+        holiday = "成人の日"
+        steps = ["next_business_day"]
+
+        [[working_days]]
+        date = "2026-08-16"
+        label = "棚卸し"
+    "#;
+
+    #[tokio::test]
+    async fn test_build_from_toml() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = CalendarDefinition::from_toml_str(TOML)
+            .unwrap()
+            .build(&client)
+            .unwrap();
+
+        // 2026年7月22日は水曜日、7月25日は土曜日
+        assert!(calendar.is_day_off(ymd(2026, 7, 22)));
+        assert!(!calendar.is_day_off(ymd(2026, 7, 25)));
+        assert!(calendar.is_day_off(ymd(2026, 6, 1)));
+        assert!(calendar.is_day_off(ymd(2026, 8, 14)));
+        assert!(!calendar.is_day_off(ymd(2026, 8, 16)));
+        assert!(calendar.is_day_off(ymd(2025, 12, 30)));
+        assert!(calendar.is_day_off(ymd(2026, 1, 13)));
+    }
+
+    #[test]
+    fn test_toml_and_json_are_equivalent() {
+        let toml = r#"
+            [[closures]]
+            date = "2026-06-01"
+            label = "創立記念日"
+
+            [[rules]]
+            nth_weekday = { month = 11, nth = 3, weekday = "fri" }
+            steps = [{ offset = 0 }]
+        "#;
+        let json = r#"{
+            "closures": [{ "date": "2026-06-01", "label": "創立記念日" }],
+            "rules": [{ "nth_weekday": { "month": 11, "nth": 3, "weekday": "fri" }, "steps": [{ "offset": 0 }] }]
+        }"#;
+
+        assert_eq!(
+            CalendarDefinition::from_toml_str(toml).unwrap(),
+            CalendarDefinition::from_json_str(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_yaml_and_json_are_equivalent() {
+        let yaml = r#"
+            closures:
+              - date: "2026-06-01"
+                label: 創立記念日
+            rules:
+              - nth_weekday: { month: 11, nth: 3, weekday: fri }
+                steps:
+                  - offset: 0
+        "#;
+        let json = r#"{
+            "closures": [{ "date": "2026-06-01", "label": "創立記念日" }],
+            "rules": [{ "nth_weekday": { "month": 11, "nth": 3, "weekday": "fri" }, "steps": [{ "offset": 0 }] }]
+        }"#;
+
+        assert_eq!(
+            CalendarDefinition::from_yaml_str(yaml).unwrap(),
+            CalendarDefinition::from_json_str(json).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validation_error_points_to_entry() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let definition = CalendarDefinition::from_toml_str(
+            r#"
+            [[closures]]
+            date = "2026-06-01"
+
+            [[closures]]
+            start = "2026-08-16"
+            end = "2026-08-13"
+            "#,
+        )
+        .unwrap();
+
+        match definition.build(&client) {
+            Err(crate::error::Error::Config(message)) => {
+                assert!(message.starts_with("closures[1]"), "{}", message)
+            }
+            _ => panic!("検証エラーになるはずです"),
+        }
+
+        let definition = CalendarDefinition::from_toml_str(
+            r#"
+            [[rules]]
+            holiday = "存在しない日"
+            "#,
+        )
+        .unwrap();
+
+        match definition.build(&client) {
+            Err(crate::error::Error::Config(message)) => {
+                assert!(message.starts_with("rules[0].holiday"), "{}", message)
+            }
+            _ => panic!("検証エラーになるはずです"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+/// 休日にあたる日付を営業日に調整する方法 (営業日調整規則) です。
+///
+/// `crate::business::BusinessCalendar::adjust()` に渡して使用します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BusinessDayConvention {
+    /// 翌営業日に調整します。
+    Following,
+
+    /// 翌営業日に調整します。ただし月が変わる場合は前営業日に調整します。
+    #[default]
+    ModifiedFollowing,
+
+    /// 前営業日に調整します。
+    Preceding,
+
+    /// 前営業日に調整します。ただし月が変わる場合は翌営業日に調整します。
+    ModifiedPreceding,
+
+    /// 調整しません。
+    Unadjusted,
+}
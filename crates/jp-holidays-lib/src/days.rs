@@ -0,0 +1,276 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 日付の種別です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayKind {
+    /// 平日 (月曜日から金曜日)
+    Weekday,
+
+    /// 土曜日
+    Saturday,
+
+    /// 日曜日
+    Sunday,
+
+    /// 国民の祝日
+    NationalHoliday,
+
+    /// 振替休日
+    SubstituteHoliday,
+
+    /// 国民の休日
+    CitizensHoliday,
+
+    /// 皇室の慶弔行事など、特別法により一度限り休日となった日
+    SpecialHoliday,
+
+    /// 上記以外の休日 (会社独自の休業日・年末年始の休日など)
+    Closure,
+}
+
+impl DayKind {
+    /// カレンダーと日付を渡して、日付の種別を判定します。
+    ///
+    /// 祝日は曜日より優先します (日曜日の祝日は `DayKind::NationalHoliday` です)。
+    /// 祝日でも土日でもない休日は `DayKind::Closure` です。
+    pub fn of<C: crate::business::BusinessCalendar + ?Sized>(
+        calendar: &C,
+        date: NaiveDate,
+    ) -> Self {
+        if let Some(holiday) = calendar.get_holiday_detail(date) {
+            return match holiday.kind {
+                crate::holiday::HolidayKind::National => DayKind::NationalHoliday,
+                crate::holiday::HolidayKind::Substitute => DayKind::SubstituteHoliday,
+                crate::holiday::HolidayKind::Citizens => DayKind::CitizensHoliday,
+                crate::holiday::HolidayKind::Special => DayKind::SpecialHoliday,
+            };
+        }
+
+        match date.weekday() {
+            chrono::Weekday::Sat => DayKind::Saturday,
+            chrono::Weekday::Sun => DayKind::Sunday,
+            _ if calendar.is_day_off(date) => DayKind::Closure,
+            _ => DayKind::Weekday,
+        }
+    }
+
+    /// 祝日 (国民の祝日・振替休日・国民の休日・特別な休日) かどうかを判定します。
+    pub fn is_holiday(&self) -> bool {
+        matches!(
+            self,
+            DayKind::NationalHoliday
+                | DayKind::SubstituteHoliday
+                | DayKind::CitizensHoliday
+                | DayKind::SpecialHoliday
+        )
+    }
+}
+
+/// 種別を判定した日付です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Day {
+    /// 日付
+    pub date: NaiveDate,
+
+    /// 日付の種別
+    pub kind: DayKind,
+
+    /// カレンダー上の休日かどうか
+    ///
+    /// 出勤日を設定した土曜日のように、種別と一致しない場合があります。
+    pub is_day_off: bool,
+}
+
+/// 期間内の日付を、種別を判定しながら順に返すイテレーターです。
+///
+/// `crate::business::BusinessCalendar::classify_days()` で作成します。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use jp_holidays_lib::{client::Client, days::DayKind};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     let start = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("存在しない日付です".to_string())?;
+///     let end = NaiveDate::from_ymd_opt(2026, 5, 31).ok_or("存在しない日付です".to_string())?;
+///
+///     for day in client.classify_days(start..=end)?.days_off() {
+///         println!("{} | {:?}", day.date, day.kind);
+///     }
+///
+///     let substitutes = client
+///         .classify_days(start..=end)?
+///         .of_kind(DayKind::SubstituteHoliday)
+///         .count();
+///     println!("5月の振替休日は {} 日です", substitutes);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Days<'a, C: ?Sized> {
+    calendar: &'a C,
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+impl<C: ?Sized> Clone for Days<'_, C> {
+    fn clone(&self) -> Self {
+        Self {
+            calendar: self.calendar,
+            next: self.next,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a, C: crate::business::BusinessCalendar + ?Sized> Days<'a, C> {
+    /// 期間を検証して作成します。期間の一部でも範囲外の場合は `crate::error::Error::OutOfRange` を返します。
+    pub(crate) fn new(
+        calendar: &'a C,
+        period: std::ops::RangeInclusive<NaiveDate>,
+    ) -> Result<Self, crate::error::Error> {
+        let (start, end) = period.into_inner();
+        if start <= end {
+            crate::business::ensure_covered(calendar, start)?;
+            crate::business::ensure_covered(calendar, end)?;
+        }
+
+        Ok(Self {
+            calendar,
+            next: (start <= end).then_some(start),
+            end,
+        })
+    }
+
+    /// 営業日のみを返します。
+    pub fn business_days(self) -> impl Iterator<Item = Day> + 'a {
+        self.filter(|day| !day.is_day_off)
+    }
+
+    /// 休日のみを返します。
+    pub fn days_off(self) -> impl Iterator<Item = Day> + 'a {
+        self.filter(|day| day.is_day_off)
+    }
+
+    /// 祝日 (国民の祝日・振替休日・国民の休日・特別な休日) のみを返します。
+    pub fn holidays(self) -> impl Iterator<Item = Day> + 'a {
+        self.filter(|day| day.kind.is_holiday())
+    }
+
+    /// 種別を指定して、その種別の日のみを返します。
+    pub fn of_kind(self, kind: DayKind) -> impl Iterator<Item = Day> + 'a {
+        self.filter(move |day| day.kind == kind)
+    }
+}
+
+impl<C: crate::business::BusinessCalendar + ?Sized> Iterator for Days<'_, C> {
+    type Item = Day;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.next?;
+        self.next = date.succ_opt().filter(|next| *next <= self.end);
+
+        Some(Day {
+            date,
+            kind: DayKind::of(self.calendar, date),
+            is_day_off: self.calendar.is_day_off(date),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self
+            .next
+            .map_or(0, |next| (self.end - next).num_days() as usize + 1);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<C: crate::business::BusinessCalendar + ?Sized> ExactSizeIterator for Days<'_, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::BusinessCalendar;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_classify_days() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let days: Vec<Day> = client
+            .classify_days(ymd(2026, 5, 1)..=ymd(2026, 5, 7))
+            .unwrap()
+            .collect();
+
+        let kinds: Vec<DayKind> = days.iter().map(|day| day.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DayKind::Weekday,
+                DayKind::Saturday,
+                DayKind::NationalHoliday,
+                DayKind::NationalHoliday,
+                DayKind::NationalHoliday,
+                DayKind::SubstituteHoliday,
+                DayKind::Weekday,
+            ]
+        );
+        assert_eq!(
+            client
+                .classify_days(ymd(2026, 9, 1)..=ymd(2026, 9, 30))
+                .unwrap()
+                .of_kind(DayKind::CitizensHoliday)
+                .map(|day| day.date)
+                .collect::<Vec<_>>(),
+            vec![ymd(2026, 9, 22)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_adaptors_with_overlay() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::overlay::OverlayCalendar::new(&client)
+            .with_closures_between(ymd(2026, 8, 12)..=ymd(2026, 8, 14), "お盆休み")
+            .with_working_day(ymd(2026, 8, 15), "棚卸し");
+
+        let days = calendar
+            .classify_days(ymd(2026, 8, 10)..=ymd(2026, 8, 16))
+            .unwrap();
+        assert_eq!(days.len(), 7);
+
+        let business_days: Vec<NaiveDate> =
+            days.clone().business_days().map(|day| day.date).collect();
+        assert_eq!(business_days, vec![ymd(2026, 8, 10), ymd(2026, 8, 15)]);
+
+        let days_off: Vec<DayKind> = days.days_off().map(|day| day.kind).collect();
+        assert_eq!(
+            days_off,
+            vec![
+                DayKind::NationalHoliday,
+                DayKind::Closure,
+                DayKind::Closure,
+                DayKind::Closure,
+                DayKind::Sunday,
+            ]
+        );
+
+        assert!(
+            client
+                .classify_days(ymd(2027, 1, 1)..=ymd(2027, 1, 2))
+                .is_err()
+        );
+        assert_eq!(
+            client
+                .classify_days(ymd(2026, 1, 2)..=ymd(2026, 1, 1))
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+}
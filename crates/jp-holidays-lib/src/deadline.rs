@@ -0,0 +1,233 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 期限の計算に使う期間です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    /// 日によって定めた期間
+    Days(u32),
+
+    /// 週によって定めた期間
+    Weeks(u32),
+
+    /// 月によって定めた期間
+    Months(u32),
+
+    /// 年によって定めた期間
+    Years(u32),
+}
+
+/// 民法第140条から第143条の規定に基づいて、期間の末日 (期限) を計算します。
+///
+/// - 初日は算入しません (民法第140条)。午前零時から始まる期間の場合は `including_first_day()` を指定します。
+/// - 日・週で定めた期間は、起算日から数えて期間の末日に満了します。
+/// - 月・年で定めた期間は、最後の月・年において起算日に応当する日の前日に満了します。
+///   応当する日がない場合は、その月の末日に満了します (民法第143条)。
+/// - 期間の末日が休日の場合は、その翌日 (翌営業日) に満了します (民法第142条、国税通則法第10条第2項)。
+///   延長しない場合は `without_extension()` を指定します。
+///
+/// 休日の判定には任意の `crate::business::BusinessCalendar` を使用できます。
+/// 行政機関への申請や届出の期限には `crate::administrative::AdministrativeCalendar` を使用します。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use jp_holidays_lib::{
+///     administrative::AdministrativeCalendar,
+///     client::Client,
+///     deadline::{Deadline, Period},
+/// };
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///     let calendar = AdministrativeCalendar::new(&client);
+///
+///     // 2025年11月28日に通知を受けた日から1か月以内
+///     let date = NaiveDate::from_ymd_opt(2025, 11, 28).ok_or("存在しない日付です".to_string())?;
+///     let deadline = Deadline::new(Period::Months(1)).compute(&calendar, date)?;
+///
+///     println!("期限は {}", deadline);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deadline {
+    period: Period,
+    include_first_day: bool,
+    extend_on_day_off: bool,
+}
+
+impl Deadline {
+    /// 期間を渡して初期化します。初日不算入で、末日が休日の場合は延長します。
+    pub fn new(period: Period) -> Self {
+        Self {
+            period,
+            include_first_day: false,
+            extend_on_day_off: true,
+        }
+    }
+
+    /// 初日を算入します。期間が午前零時から始まる場合に指定します。
+    pub fn including_first_day(mut self) -> Self {
+        self.include_first_day = true;
+        self
+    }
+
+    /// 末日が休日であっても延長しません。
+    pub fn without_extension(mut self) -> Self {
+        self.extend_on_day_off = false;
+        self
+    }
+
+    /// 期間を取得します。
+    pub fn period(&self) -> Period {
+        self.period
+    }
+
+    /// 期間の起算日を取得します。
+    pub fn commencement(&self, date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        if self.include_first_day {
+            Ok(date)
+        } else {
+            date.succ_opt().ok_or_else(|| {
+                crate::error::Error::OutOfRange(format!("{} の翌日がありません", date))
+            })
+        }
+    }
+
+    /// 休日による延長を行う前の、期間の末日を取得します。
+    pub fn expiry(&self, date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        let start = self.commencement(date)?;
+        let overflow =
+            || crate::error::Error::OutOfRange(format!("{} から期間を計算できません", date));
+
+        match self.period {
+            Period::Days(days) => start
+                .checked_add_days(chrono::Days::new(days as u64))
+                .and_then(|date| date.pred_opt())
+                .ok_or_else(overflow),
+            Period::Weeks(weeks) => start
+                .checked_add_days(chrono::Days::new(weeks as u64 * 7))
+                .and_then(|date| date.pred_opt())
+                .ok_or_else(overflow),
+            Period::Months(months) => corresponding_day_before(start, months).ok_or_else(overflow),
+            Period::Years(years) => {
+                corresponding_day_before(start, years.checked_mul(12).ok_or_else(overflow)?)
+                    .ok_or_else(overflow)
+            }
+        }
+    }
+
+    /// `date` を期間の初日として、期限を計算します。
+    ///
+    /// 期間の末日が `calendar` の休日の場合は、翌営業日を返します。
+    /// 休日の判定に祝日データの範囲外の日付が必要な場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn compute<C: crate::business::BusinessCalendar + ?Sized>(
+        &self,
+        calendar: &C,
+        date: NaiveDate,
+    ) -> Result<NaiveDate, crate::error::Error> {
+        let expiry = self.expiry(date)?;
+
+        if !self.extend_on_day_off {
+            return Ok(expiry);
+        }
+
+        crate::business::ensure_covered(calendar, expiry)?;
+        if calendar.is_day_off(expiry) {
+            calendar.next_business_day(expiry)
+        } else {
+            Ok(expiry)
+        }
+    }
+}
+
+/// `start` から `months` か月後の応当日の前日を取得します。応当日がない場合はその月の末日です。
+fn corresponding_day_before(start: NaiveDate, months: u32) -> Option<NaiveDate> {
+    let index = start.year() as i64 * 12 + start.month0() as i64 + months as i64;
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = index.rem_euclid(12) as u32 + 1;
+
+    match NaiveDate::from_ymd_opt(year, month, start.day()) {
+        Some(date) => date.pred_opt(),
+        None => NaiveDate::from_ymd_opt(year, month, 1)?
+            .checked_add_months(chrono::Months::new(1))?
+            .pred_opt(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_days_extended_over_golden_week() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::administrative::AdministrativeCalendar::new(&client);
+
+        // 2026年4月26日から7日間: 起算日は27日、末日は5月3日 (日・憲法記念日)
+        let deadline = Deadline::new(Period::Days(7));
+        assert_eq!(deadline.expiry(ymd(2026, 4, 26)).unwrap(), ymd(2026, 5, 3));
+        assert_eq!(
+            deadline.compute(&calendar, ymd(2026, 4, 26)).unwrap(),
+            ymd(2026, 5, 7)
+        );
+        assert_eq!(
+            deadline
+                .including_first_day()
+                .compute(&calendar, ymd(2026, 4, 26))
+                .unwrap(),
+            ymd(2026, 5, 7)
+        );
+        assert_eq!(
+            Deadline::new(Period::Weeks(1))
+                .without_extension()
+                .compute(&calendar, ymd(2026, 4, 26))
+                .unwrap(),
+            ymd(2026, 5, 3)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_months_without_corresponding_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::administrative::AdministrativeCalendar::new(&client);
+
+        // 起算日は1月31日、2月31日はないため2月末日 (28日・土) に満了し、3月2日 (月) に延長
+        let deadline = Deadline::new(Period::Months(1));
+        assert_eq!(deadline.expiry(ymd(2026, 1, 30)).unwrap(), ymd(2026, 2, 28));
+        assert_eq!(
+            deadline.compute(&calendar, ymd(2026, 1, 30)).unwrap(),
+            ymd(2026, 3, 2)
+        );
+
+        // 起算日は4月1日、月の初めから起算するため4月30日に満了
+        assert_eq!(deadline.expiry(ymd(2026, 3, 31)).unwrap(), ymd(2026, 4, 30));
+    }
+
+    #[tokio::test]
+    async fn test_extended_over_year_end() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::administrative::AdministrativeCalendar::new(&client);
+
+        // 起算日は11月29日、末日は12月28日 (日)、12月29日から1月4日まで閉庁
+        assert_eq!(
+            Deadline::new(Period::Months(1))
+                .compute(&calendar, ymd(2025, 11, 28))
+                .unwrap(),
+            ymd(2026, 1, 5)
+        );
+        assert_eq!(
+            Deadline::new(Period::Years(1))
+                .expiry(ymd(2024, 2, 28))
+                .unwrap(),
+            ymd(2025, 2, 28)
+        );
+    }
+}
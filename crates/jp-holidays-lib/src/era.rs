@@ -0,0 +1,290 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 元号です。
+///
+/// 改元の日から新しい元号になります (大正以降は改元の日の前日までが前の元号です)。
+/// 明治6年 (1873年) より前の日付は、当時の太陰太陽暦ではなくグレゴリオ暦の日付として扱います。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Era {
+    /// 明治 (1868年10月23日〜)
+    Meiji,
+
+    /// 大正 (1912年7月30日〜)
+    Taisho,
+
+    /// 昭和 (1926年12月25日〜)
+    Showa,
+
+    /// 平成 (1989年1月8日〜)
+    Heisei,
+
+    /// 令和 (2019年5月1日〜)
+    Reiwa,
+}
+
+impl Era {
+    /// すべての元号を古い順に取得します。
+    pub fn all() -> [Era; 5] {
+        [Era::Meiji, Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa]
+    }
+
+    /// 元号の名称を取得します。
+    pub fn name(&self) -> &'static str {
+        match self {
+            Era::Meiji => "明治",
+            Era::Taisho => "大正",
+            Era::Showa => "昭和",
+            Era::Heisei => "平成",
+            Era::Reiwa => "令和",
+        }
+    }
+
+    /// 元号の初日を取得します。
+    pub fn start(&self) -> NaiveDate {
+        let (year, month, day) = match self {
+            Era::Meiji => (1868, 10, 23),
+            Era::Taisho => (1912, 7, 30),
+            Era::Showa => (1926, 12, 25),
+            Era::Heisei => (1989, 1, 8),
+            Era::Reiwa => (2019, 5, 1),
+        };
+        NaiveDate::from_ymd_opt(year, month, day).expect("改元の日は有効な日付です")
+    }
+
+    /// 元号の最終日を取得します。現在の元号の場合は `None` を返します。
+    pub fn end(&self) -> Option<NaiveDate> {
+        Self::all()
+            .into_iter()
+            .find(|era| era > self)
+            .and_then(|next| next.start().pred_opt())
+    }
+
+    /// `chrono::NaiveDate` を渡して、その日の元号を取得します。明治より前の日付は `None` を返します。
+    pub fn of(date: NaiveDate) -> Option<Era> {
+        Self::all()
+            .into_iter()
+            .rev()
+            .find(|era| era.start() <= date)
+    }
+}
+
+/// 和暦の日付です。
+///
+/// `EraDate::new()` または `EraDate::from_date()` で作成するため、常に元号の期間内の存在する日付です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EraDate {
+    era: Era,
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl EraDate {
+    /// 元号と年月日を渡して初期化します。
+    ///
+    /// 存在しない日付や、元号の期間外の日付 (平成31年5月1日など) の場合は
+    /// `crate::error::Error::InvalidDate` を返します。
+    pub fn new(era: Era, year: u32, month: u32, day: u32) -> Result<Self, crate::error::Error> {
+        let invalid = || {
+            crate::error::Error::InvalidDate(format!(
+                "不正な和暦の日付です: {}{}年 {}月 {}日",
+                era.name(),
+                year,
+                month,
+                day
+            ))
+        };
+
+        let gregorian_year = i32::try_from(year)
+            .ok()
+            .filter(|year| *year >= 1)
+            .and_then(|year| era.start().year().checked_add(year - 1))
+            .ok_or_else(invalid)?;
+        let date = NaiveDate::from_ymd_opt(gregorian_year, month, day).ok_or_else(invalid)?;
+
+        if Era::of(date) != Some(era) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            era,
+            year,
+            month,
+            day,
+        })
+    }
+
+    /// `chrono::NaiveDate` を渡して、和暦の日付に変換します。
+    ///
+    /// 明治より前の日付の場合は `crate::error::Error::InvalidDate` を返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::era::EraDate;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let date = NaiveDate::from_ymd_opt(2019, 5, 1).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     // 令和元年5月1日
+    ///     println!("{}", EraDate::from_date(date)?.format());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_date(date: NaiveDate) -> Result<Self, crate::error::Error> {
+        let era = Era::of(date).ok_or(crate::error::Error::InvalidDate(format!(
+            "明治より前の日付は和暦に変換できません: {}",
+            date
+        )))?;
+
+        Ok(Self {
+            era,
+            year: (date.year() - era.start().year() + 1) as u32,
+            month: date.month(),
+            day: date.day(),
+        })
+    }
+
+    /// 元号を取得します。
+    pub fn era(&self) -> Era {
+        self.era
+    }
+
+    /// 元号の年を取得します (元年は1)。
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 月を取得します。
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// 日を取得します。
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// 西暦の `chrono::NaiveDate` に変換します。
+    pub fn to_date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(
+            self.era.start().year() + self.year as i32 - 1,
+            self.month,
+            self.day,
+        )
+        .expect("和暦の日付は初期化時に検証済みです")
+    }
+
+    /// 元号と年を、元年の表記を含めて取得します (`令和元年`、`令和8年` など)。
+    pub fn era_year(&self) -> String {
+        if self.year == 1 {
+            format!("{}元年", self.era.name())
+        } else {
+            format!("{}{}年", self.era.name(), self.year)
+        }
+    }
+
+    /// `令和8年5月3日` の形式の文字列を取得します。
+    pub fn format(&self) -> String {
+        format!("{}{}月{}日", self.era_year(), self.month, self.day)
+    }
+}
+
+/// `chrono::NaiveDate` を渡して、`令和8年5月3日(日)` の形式の文字列を取得します。
+///
+/// 祝日名も含める場合は `crate::client::Client::format_japanese()` を使用します。
+/// 明治より前の日付の場合は `crate::error::Error::InvalidDate` を返します。
+pub fn format_with_weekday(date: NaiveDate) -> Result<String, crate::error::Error> {
+    Ok(format!(
+        "{}({})",
+        EraDate::from_date(date)?.format(),
+        crate::explain::weekday_name(date.weekday())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_era_transitions() {
+        let cases = [
+            (ymd(1912, 7, 29), "明治45年7月29日"),
+            (ymd(1912, 7, 30), "大正元年7月30日"),
+            (ymd(1926, 12, 24), "大正15年12月24日"),
+            (ymd(1926, 12, 25), "昭和元年12月25日"),
+            (ymd(1989, 1, 7), "昭和64年1月7日"),
+            (ymd(1989, 1, 8), "平成元年1月8日"),
+            (ymd(2019, 4, 30), "平成31年4月30日"),
+            (ymd(2019, 5, 1), "令和元年5月1日"),
+            (ymd(2026, 5, 3), "令和8年5月3日"),
+        ];
+
+        for (date, expected) in cases {
+            let era_date = EraDate::from_date(date).unwrap();
+            assert_eq!(era_date.format(), expected);
+            assert_eq!(era_date.to_date(), date);
+        }
+
+        assert!(EraDate::from_date(ymd(1868, 10, 22)).is_err());
+        assert_eq!(Era::Showa.end(), Some(ymd(1989, 1, 7)));
+        assert_eq!(Era::Reiwa.end(), None);
+    }
+
+    #[test]
+    fn test_new_validates_era_range() {
+        assert_eq!(
+            EraDate::new(Era::Heisei, 1, 1, 8).unwrap().to_date(),
+            ymd(1989, 1, 8)
+        );
+        let era_date = EraDate::from_date(ymd(2026, 5, 3)).unwrap();
+        assert_eq!(
+            (
+                era_date.era(),
+                era_date.year(),
+                era_date.month(),
+                era_date.day()
+            ),
+            (Era::Reiwa, 8, 5, 3)
+        );
+        assert!(EraDate::new(Era::Heisei, 1, 1, 7).is_err());
+        assert!(EraDate::new(Era::Heisei, 31, 5, 1).is_err());
+        assert!(EraDate::new(Era::Reiwa, 1, 4, 30).is_err());
+        assert!(EraDate::new(Era::Reiwa, 0, 5, 1).is_err());
+        assert!(EraDate::new(Era::Reiwa, 8, 2, 30).is_err());
+        assert!(EraDate::new(Era::Reiwa, i32::MAX as u32, 1, 1).is_err());
+        assert!(EraDate::new(Era::Reiwa, u32::MAX, 1, 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_format_japanese() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        assert_eq!(
+            client.format_japanese(ymd(2026, 5, 3)).unwrap(),
+            "令和8年5月3日(日・憲法記念日)"
+        );
+        assert_eq!(
+            client.format_japanese(ymd(2026, 5, 6)).unwrap(),
+            "令和8年5月6日(水・振替休日)"
+        );
+        assert_eq!(
+            client.format_japanese(ymd(2026, 9, 22)).unwrap(),
+            "令和8年9月22日(火・国民の休日)"
+        );
+        assert_eq!(
+            client.format_japanese(ymd(2026, 5, 7)).unwrap(),
+            "令和8年5月7日(木)"
+        );
+        assert_eq!(
+            format_with_weekday(ymd(2019, 5, 1)).unwrap(),
+            "令和元年5月1日(水)"
+        );
+    }
+}
@@ -11,4 +11,10 @@ pub enum Error {
 
     #[error("不正な日付: {0}")]
     InvalidDate(String),
+
+    #[error("祝日データの範囲外: {0}")]
+    OutOfRange(String),
+
+    #[error("カレンダー定義が不正: {0}")]
+    Config(String),
 }
@@ -0,0 +1,257 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 土曜日の半日立会が廃止された日 (1989年2月11日) です。
+const SATURDAY_SESSION_END: (i32, u32, u32) = (1989, 2, 11);
+
+/// 受渡日が約定日から2営業日目 (T+2) になった最初の約定日 (2019年7月16日) です。
+const SETTLEMENT_T2_START: (i32, u32, u32) = (2019, 7, 16);
+
+/// 取引所の立会の種類です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Session {
+    /// 休場日
+    Closed,
+
+    /// 半日立会 (1989年2月11日より前の土曜日)
+    HalfDay,
+
+    /// 通常の立会
+    FullDay,
+}
+
+/// 東京証券取引所の立会日のカレンダーです。
+///
+/// 以下の日を休場日とします。
+///
+/// - 日曜日と土曜日 (1989年2月11日より前の土曜日は半日立会です)
+/// - 国民の祝日に関する法律に規定する休日 (振替休日と国民の休日を含みます)
+/// - 12月31日から翌年1月3日まで
+///
+/// 大発会・大納会の半日立会や臨時の休場は考慮しません。
+/// 立会日の計算は `crate::business::BusinessCalendar` のメソッドで行います (半日立会の日も立会日として数えます)。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use jp_holidays_lib::{business::BusinessCalendar, client::Client, exchange::ExchangeCalendar};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///     let calendar = ExchangeCalendar::new(&client);
+///
+///     let date = NaiveDate::from_ymd_opt(2026, 4, 30).ok_or("存在しない日付です".to_string())?;
+///     println!("2026年 4月 30日 の翌立会日は {}", calendar.next_business_day(date)?);
+///     println!("2026年 4月 30日 約定の受渡日は {}", calendar.settlement_date(date)?);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct ExchangeCalendar<'a> {
+    client: &'a crate::client::Client,
+}
+
+impl<'a> ExchangeCalendar<'a> {
+    /// 祝日データを渡して初期化します。
+    pub fn new(client: &'a crate::client::Client) -> Self {
+        Self { client }
+    }
+
+    /// カレンダーが参照している `Client` を取得します。
+    pub fn client(&self) -> &'a crate::client::Client {
+        self.client
+    }
+
+    /// `chrono::NaiveDate` を渡して立会の種類を取得します。
+    pub fn session(&self, date: NaiveDate) -> Session {
+        if self.client.is_holiday(date) || crate::bank::BankCalendar::is_year_end_holiday(date) {
+            return Session::Closed;
+        }
+
+        match date.weekday() {
+            chrono::Weekday::Sun => Session::Closed,
+            chrono::Weekday::Sat if date < saturday_session_end() => Session::HalfDay,
+            chrono::Weekday::Sat => Session::Closed,
+            _ => Session::FullDay,
+        }
+    }
+
+    /// 約定日から受渡日までの立会日数を取得します。
+    ///
+    /// 2019年7月16日以降の約定は2営業日目 (T+2)、それより前の約定は3営業日目 (T+3) です。
+    pub fn settlement_cycle(trade_date: NaiveDate) -> i64 {
+        if trade_date < settlement_t2_start() {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// 約定日を渡して受渡日を取得します。
+    ///
+    /// 約定日から `settlement_cycle()` 立会日後の日付です。約定日が休場日の場合も、その日から数えます。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, exchange::ExchangeCalendar};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///     let calendar = ExchangeCalendar::new(&client);
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2025, 12, 29).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2025年 12月 29日 約定の受渡日は {}", calendar.settlement_date(date)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn settlement_date(&self, trade_date: NaiveDate) -> Result<NaiveDate, crate::error::Error> {
+        crate::business::BusinessCalendar::add_business_days(
+            self,
+            trade_date,
+            Self::settlement_cycle(trade_date),
+        )
+    }
+}
+
+impl crate::business::BusinessCalendar for ExchangeCalendar<'_> {
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        self.session(date) == Session::Closed
+    }
+
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        self.client.coverage()
+    }
+
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.client.get_holiday_detail(date)
+    }
+
+    fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        // 1989年2月より前の土曜日は半日立会のため、休日の理由になりません。
+        let weekend = match date.weekday() {
+            chrono::Weekday::Sun => true,
+            chrono::Weekday::Sat => date >= saturday_session_end(),
+            _ => false,
+        };
+        let mut reasons = crate::explain::calendar_reasons(self.client, date, weekend);
+        if crate::bank::BankCalendar::is_year_end_holiday(date) {
+            reasons.push(crate::explain::Reason::YearEnd {
+                regulation: "取引所の業務規程",
+            });
+        }
+        reasons
+    }
+
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end <= start {
+            return 0;
+        }
+
+        // 土日と祝日を休日とした日数から、半日立会の土曜日を除き、平日の年末年始を加えます。
+        let saturday_end = end.min(saturday_session_end());
+        let half_days = if start < saturday_end {
+            let saturdays = crate::business::count_weekdays_matching(start, saturday_end, |w| {
+                w == chrono::Weekday::Sat
+            });
+            let holidays = self
+                .client
+                .list_holidays()
+                .range(start..saturday_end)
+                .filter(|(date, _)| date.weekday() == chrono::Weekday::Sat)
+                .count() as i64;
+            let year_end = crate::bank::year_end_holidays_between(
+                &crate::bank::YEAR_END_HOLIDAYS,
+                start,
+                saturday_end,
+            )
+            .filter(|date| date.weekday() == chrono::Weekday::Sat)
+            .filter(|date| !self.client.is_holiday(*date))
+            .count() as i64;
+            let closed_saturdays = holidays + year_end;
+            saturdays - closed_saturdays
+        } else {
+            0
+        };
+
+        let year_end =
+            crate::bank::year_end_holidays_between(&crate::bank::YEAR_END_HOLIDAYS, start, end)
+                .filter(|date| !self.client.is_day_off(*date))
+                .count() as i64;
+
+        self.client.count_days_off(start, end) - half_days + year_end
+    }
+}
+
+fn saturday_session_end() -> NaiveDate {
+    let (year, month, day) = SATURDAY_SESSION_END;
+    NaiveDate::from_ymd_opt(year, month, day)
+        .expect("土曜日の半日立会が廃止された日は存在する日付です")
+}
+
+fn settlement_t2_start() -> NaiveDate {
+    let (year, month, day) = SETTLEMENT_T2_START;
+    NaiveDate::from_ymd_opt(year, month, day).expect("T+2 の開始日は存在する日付です")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_session() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = ExchangeCalendar::new(&client);
+
+        assert_eq!(calendar.session(ymd(2026, 1, 2)), Session::Closed);
+        assert_eq!(calendar.session(ymd(2026, 1, 5)), Session::FullDay);
+        assert_eq!(calendar.session(ymd(2026, 1, 10)), Session::Closed);
+        // 1960年1月9日は土曜日
+        assert_eq!(calendar.session(ymd(1960, 1, 9)), Session::HalfDay);
+        // 1960年1月2日は土曜日だが年始の休場日
+        assert_eq!(calendar.session(ymd(1960, 1, 2)), Session::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_settlement_date() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = ExchangeCalendar::new(&client);
+
+        // 2025年12月29日 (月) 約定は年末年始の休場をはさみ 2026年1月5日 (月) 受渡
+        assert_eq!(
+            calendar.settlement_date(ymd(2025, 12, 29)).unwrap(),
+            ymd(2026, 1, 5)
+        );
+        // 2019年7月12日 (金) 約定は T+3、海の日 (15日) をはさみ 18日 受渡
+        assert_eq!(
+            calendar.settlement_date(ymd(2019, 7, 12)).unwrap(),
+            ymd(2019, 7, 18)
+        );
+        assert_eq!(
+            calendar.settlement_date(ymd(2019, 7, 16)).unwrap(),
+            ymd(2019, 7, 18)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_days_off_matches_day_by_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = ExchangeCalendar::new(&client);
+
+        for (start, end) in [
+            (ymd(1955, 1, 1), ymd(1965, 12, 31)),
+            (ymd(2019, 6, 1), ymd(2026, 12, 31)),
+        ] {
+            crate::business::assert_count_days_off_matches_day_by_day(&calendar, start, end);
+        }
+    }
+}
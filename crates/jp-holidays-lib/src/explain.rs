@@ -0,0 +1,196 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 休日の理由がどこから来たかを表します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// 曜日の設定 (週休日)
+    WorkWeek,
+
+    /// 内閣府が公開している「国民の祝日」CSV
+    HolidayData,
+
+    /// 法令や規程 (規定の名称)
+    Regulation(&'static str),
+
+    /// `crate::overlay::OverlayCalendar` に設定した会社独自の休業日・出勤日 (ルールによるものを含みます)
+    Overlay,
+}
+
+impl Source {
+    /// 表示用の説明を取得します。
+    pub fn description(&self) -> &'static str {
+        match self {
+            Source::WorkWeek => "曜日の設定",
+            Source::HolidayData => "内閣府「国民の祝日」CSV",
+            Source::Regulation(name) => name,
+            Source::Overlay => "会社カレンダーの設定",
+        }
+    }
+}
+
+/// 日付が休日 (または営業日) になる理由です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// 週休日 (土曜日・日曜日など)
+    Weekend(chrono::Weekday),
+
+    /// 国民の祝日・国民の休日・特別な休日
+    Holiday(crate::holiday::Holiday),
+
+    /// 振替休日
+    Substitute {
+        /// 振替休日
+        holiday: crate::holiday::Holiday,
+
+        /// 振り替えられた元の祝日
+        original: Option<crate::holiday::Holiday>,
+    },
+
+    /// 年末年始の休日
+    YearEnd {
+        /// 根拠となる規定の名称
+        regulation: &'static str,
+    },
+
+    /// 会社独自の休業日
+    Closure {
+        /// 休業日の名称
+        label: String,
+    },
+
+    /// 休日であっても営業する日 (出勤日)。他の理由を打ち消します。
+    WorkingDay {
+        /// 出勤日の名称
+        label: String,
+    },
+}
+
+impl Reason {
+    /// 理由がどこから来たかを取得します。
+    pub fn source(&self) -> Source {
+        match self {
+            Reason::Weekend(_) => Source::WorkWeek,
+            Reason::Holiday(_) | Reason::Substitute { .. } => Source::HolidayData,
+            Reason::YearEnd { regulation } => Source::Regulation(regulation),
+            Reason::Closure { .. } | Reason::WorkingDay { .. } => Source::Overlay,
+        }
+    }
+
+    /// 表示用の説明を取得します。
+    pub fn description(&self) -> String {
+        match self {
+            Reason::Weekend(weekday) => format!("{}曜日", weekday_name(*weekday)),
+            Reason::Holiday(holiday) => {
+                let kind = match holiday.kind {
+                    crate::holiday::HolidayKind::Citizens => "国民の休日",
+                    crate::holiday::HolidayKind::Special => "特別な休日",
+                    _ => "国民の祝日",
+                };
+                format!("{} ({})", holiday.name, kind)
+            }
+            Reason::Substitute { original, .. } => match original {
+                Some(original) => format!("振替休日 ({}の振替)", original.name),
+                None => "振替休日".to_string(),
+            },
+            Reason::YearEnd { .. } => "年末年始の休日".to_string(),
+            Reason::Closure { label } => format!("休業日: {}", label),
+            Reason::WorkingDay { label } => format!("出勤日: {}", label),
+        }
+    }
+}
+
+/// 曜日の日本語の名称 (「月」〜「日」) を取得します。
+pub(crate) fn weekday_name(weekday: chrono::Weekday) -> &'static str {
+    ["月", "火", "水", "木", "金", "土", "日"][weekday.num_days_from_monday() as usize]
+}
+
+/// 週休日と祝日データから、休日の理由を取得します。
+///
+/// `weekend` が `true` の場合は、週休日を理由に含めます。
+pub(crate) fn calendar_reasons<C: crate::business::BusinessCalendar + ?Sized>(
+    calendar: &C,
+    date: NaiveDate,
+    weekend: bool,
+) -> Vec<Reason> {
+    let mut reasons = Vec::new();
+
+    if weekend {
+        reasons.push(Reason::Weekend(date.weekday()));
+    }
+
+    if let Some(holiday) = calendar.get_holiday_detail(date) {
+        reasons.push(match holiday.kind {
+            crate::holiday::HolidayKind::Substitute => Reason::Substitute {
+                holiday: holiday.clone(),
+                original: holiday
+                    .substitute_for
+                    .and_then(|original| calendar.get_holiday_detail(original))
+                    .cloned(),
+            },
+            _ => Reason::Holiday(holiday.clone()),
+        });
+    }
+
+    reasons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::BusinessCalendar;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_explain_holidays() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        // 2026年5月3日は日曜日の憲法記念日
+        let reasons = client.explain(ymd(2026, 5, 3));
+        assert_eq!(reasons.len(), 2);
+        assert_eq!(reasons[0], Reason::Weekend(chrono::Weekday::Sun));
+        assert_eq!(reasons[1].description(), "憲法記念日 (国民の祝日)");
+        assert_eq!(reasons[1].source(), Source::HolidayData);
+
+        // 5月6日は憲法記念日の振替休日
+        let reasons = client.explain(ymd(2026, 5, 6));
+        assert_eq!(reasons.len(), 1);
+        assert_eq!(reasons[0].description(), "振替休日 (憲法記念日の振替)");
+
+        // 9月22日は国民の休日
+        assert_eq!(
+            client.explain(ymd(2026, 9, 22))[0].description(),
+            "休日 (国民の休日)"
+        );
+
+        assert!(client.explain(ymd(2026, 5, 7)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_explain_with_overlay_and_regulation() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar =
+            crate::overlay::OverlayCalendar::new(crate::bank::BankCalendar::new(&client))
+                .with_closures_between(ymd(2026, 8, 12)..=ymd(2026, 8, 14), "お盆休み")
+                .with_working_day(ymd(2026, 1, 3), "棚卸し");
+
+        let reasons = calendar.explain(ymd(2026, 8, 12));
+        assert_eq!(
+            reasons,
+            vec![Reason::Closure {
+                label: "お盆休み".to_string()
+            }]
+        );
+        assert_eq!(reasons[0].source(), Source::Overlay);
+
+        // 1月3日 (土) は出勤日が週休日と年末年始の休日を打ち消します
+        let reasons = calendar.explain(ymd(2026, 1, 3));
+        assert!(!calendar.is_day_off(ymd(2026, 1, 3)));
+        assert_eq!(reasons.len(), 3);
+        assert_eq!(reasons[0].description(), "出勤日: 棚卸し");
+        assert_eq!(reasons[1], Reason::Weekend(chrono::Weekday::Sat));
+        assert_eq!(reasons[2].source(), Source::Regulation("銀行法施行令第5条"));
+    }
+}
@@ -0,0 +1,229 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 五十日 (ごとおび) の1日分です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GotobiDate {
+    /// 本来の五十日 (5日・10日・15日・20日・25日・月末日)
+    pub nominal: NaiveDate,
+
+    /// 休日を調整した五十日
+    pub adjusted: NaiveDate,
+}
+
+impl GotobiDate {
+    /// 休日のため日付が移動したかどうかを判定します。
+    pub fn is_adjusted(&self) -> bool {
+        self.nominal != self.adjusted
+    }
+}
+
+/// 五十日 (5日・10日・15日・20日・25日・月末日) の決済日を計算するカレンダーです。
+///
+/// 五十日が休日の場合は営業日調整規則に従って移動します。
+/// 既定では 5日から25日までは翌営業日 (`Following`)、月末日は前営業日 (`Preceding`) に移動します。
+/// 休日の判定には銀行休業日 (`crate::bank::BankCalendar`) を使用しますが、`new()` で任意のカレンダーを指定できます。
+///
+/// ## 使用例
+///
+/// ```
+/// use jp_holidays_lib::{client::Client, gotobi::GotobiCalendar};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///     let gotobi = GotobiCalendar::bank(&client);
+///
+///     for date in gotobi.list_gotobi(2026, 5)? {
+///         println!("{} -> {}", date.nominal, date.adjusted);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct GotobiCalendar<C> {
+    calendar: C,
+    convention: crate::convention::BusinessDayConvention,
+    month_end_convention: crate::convention::BusinessDayConvention,
+}
+
+impl<'a> GotobiCalendar<crate::bank::BankCalendar<'a>> {
+    /// 銀行休業日をもとにした五十日のカレンダーを初期化します。
+    pub fn bank(client: &'a crate::client::Client) -> Self {
+        Self::new(crate::bank::BankCalendar::new(client))
+    }
+}
+
+impl<C: crate::business::BusinessCalendar> GotobiCalendar<C> {
+    /// 休日の判定に使うカレンダーを渡して初期化します。
+    pub fn new(calendar: C) -> Self {
+        Self {
+            calendar,
+            convention: crate::convention::BusinessDayConvention::Following,
+            month_end_convention: crate::convention::BusinessDayConvention::Preceding,
+        }
+    }
+
+    /// 5日から25日までの五十日に適用する営業日調整規則を設定します。
+    pub fn with_convention(mut self, convention: crate::convention::BusinessDayConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// 月末日に適用する営業日調整規則を設定します。
+    pub fn with_month_end_convention(
+        mut self,
+        convention: crate::convention::BusinessDayConvention,
+    ) -> Self {
+        self.month_end_convention = convention;
+        self
+    }
+
+    /// 休日の判定に使うカレンダーを取得します。
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    /// 年月を渡して、その月の五十日を取得します。
+    ///
+    /// 調整に祝日データの範囲外の日付が必要な場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn list_gotobi(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<GotobiDate>, crate::error::Error> {
+        let (_, last) = crate::business::month_bounds(year, month)?;
+
+        [5, 10, 15, 20, 25]
+            .into_iter()
+            .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+            .map(|date| (date, self.convention))
+            .chain(std::iter::once((last, self.month_end_convention)))
+            .map(|(nominal, convention)| {
+                Ok(GotobiDate {
+                    nominal,
+                    adjusted: self.calendar.adjust(nominal, convention)?,
+                })
+            })
+            .collect()
+    }
+
+    /// `chrono::NaiveDate` を渡して、その日を決済日とする五十日を取得します。
+    ///
+    /// 五十日でない場合は `None` を返します。
+    /// 本来の五十日が休日の場合、その日は五十日として扱わず、調整後の日付が五十日になります。
+    pub fn get_gotobi(&self, date: NaiveDate) -> Result<Option<GotobiDate>, crate::error::Error> {
+        // 調整によって月をまたぐ場合があるため、前月と翌月の五十日も確認します。
+        let first = date.with_day(1);
+        let previous = first.and_then(|first| first.pred_opt());
+        let next = first.and_then(|first| first.checked_add_months(chrono::Months::new(1)));
+
+        let mut candidates = self.list_gotobi(date.year(), date.month())?;
+        for neighbour in [previous, next]
+            .into_iter()
+            .flatten()
+            .filter(|neighbour| self.calendar.coverage().contains(neighbour))
+        {
+            match self.list_gotobi(neighbour.year(), neighbour.month()) {
+                Ok(dates) => candidates.extend(dates),
+                // 前月・翌月の調整が祝日データの範囲外に達する場合は、その月を確認しません。
+                Err(crate::error::Error::OutOfRange(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(candidates
+            .into_iter()
+            .find(|candidate| candidate.adjusted == date))
+    }
+
+    /// `chrono::NaiveDate` を渡して、五十日 (休日を調整した日) かどうかを判定します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, gotobi::GotobiCalendar};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///     let gotobi = GotobiCalendar::bank(&client);
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2026, 5, 11).ok_or("存在しない日付です".to_string())?;
+    ///     println!("2026年 5月 11日 は五十日{}", if gotobi.is_gotobi(date)? { "です" } else { "ではありません" });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_gotobi(&self, date: NaiveDate) -> Result<bool, crate::error::Error> {
+        Ok(self.get_gotobi(date)?.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_gotobi() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let gotobi = GotobiCalendar::bank(&client);
+
+        let adjusted: Vec<NaiveDate> = gotobi
+            .list_gotobi(2026, 5)
+            .unwrap()
+            .iter()
+            .map(|date| date.adjusted)
+            .collect();
+
+        // 2026年5月5日 (火・こどもの日)、10日 (日)、31日 (日)
+        assert_eq!(
+            adjusted,
+            vec![
+                ymd(2026, 5, 7),
+                ymd(2026, 5, 11),
+                ymd(2026, 5, 15),
+                ymd(2026, 5, 20),
+                ymd(2026, 5, 25),
+                ymd(2026, 5, 29),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_gotobi() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let gotobi = GotobiCalendar::bank(&client);
+
+        let date = gotobi.get_gotobi(ymd(2026, 5, 11)).unwrap().unwrap();
+        assert_eq!(date.nominal, ymd(2026, 5, 10));
+        assert!(date.is_adjusted());
+
+        assert!(gotobi.is_gotobi(ymd(2026, 5, 15)).unwrap());
+        assert!(!gotobi.is_gotobi(ymd(2026, 5, 10)).unwrap());
+        assert!(!gotobi.is_gotobi(ymd(2026, 5, 12)).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_gotobi_preceding_across_month() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let gotobi = GotobiCalendar::bank(&client)
+            .with_convention(crate::convention::BusinessDayConvention::Preceding)
+            .with_month_end_convention(crate::convention::BusinessDayConvention::Following);
+
+        // 2025年1月5日 (日) は、1月1日〜4日も休日のため前年12月30日 (月) に移動します。
+        let date = gotobi.get_gotobi(ymd(2024, 12, 30)).unwrap().unwrap();
+        assert_eq!(date.nominal, ymd(2025, 1, 5));
+
+        // 月末日は翌営業日に移動するため、2024年12月31日は2025年1月6日です。
+        assert_eq!(
+            gotobi.get_gotobi(ymd(2025, 1, 6)).unwrap().unwrap().nominal,
+            ymd(2024, 12, 31)
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 皇室の慶弔行事などにより一度限り休日となった日の名称です。
+const SPECIAL_HOLIDAY_NAMES: [&str; 5] = [
+    "結婚の儀",
+    "大喪の礼",
+    "即位礼正殿の儀",
+    "休日（祝日扱い）",
+    "休日(祝日扱い)",
+];
+
+/// CSV 上で振替休日・国民の休日に付けられている名称です。
+const GENERIC_HOLIDAY_NAME: &str = "休日";
+
+/// 振替休日の制度が施行された日 (昭和48年法律第10号)
+const SUBSTITUTE_RULE_START: (i32, u32, u32) = (1973, 4, 12);
+
+/// 振替休日が「直後の祝日でない日」に拡大された日 (平成17年法律第43号)
+const SUBSTITUTE_CHAIN_RULE_START: (i32, u32, u32) = (2007, 1, 1);
+
+/// 祝日の種別です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayKind {
+    /// 国民の祝日 (祝日法第2条)
+    National,
+
+    /// 振替休日 (祝日法第3条第2項)
+    Substitute,
+
+    /// 国民の休日 (祝日法第3条第3項)
+    Citizens,
+
+    /// 皇室の慶弔行事など、特別法により一度限り休日となった日
+    Special,
+}
+
+/// 名称の変更や日付の移動をまたいで祝日を識別するための ID です。
+///
+/// CSV の名称と日付から対応付けます。
+/// 例えば「体育の日」と「スポーツの日」はどちらも `HolidayId::SportsDay` になり、
+/// 「天皇誕生日」は日付にかかわらず `HolidayId::EmperorsBirthday` になります。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HolidayId {
+    /// 元日
+    NewYearsDay,
+
+    /// 成人の日
+    ComingOfAgeDay,
+
+    /// 建国記念の日
+    NationalFoundationDay,
+
+    /// 天皇誕生日 (4月29日、12月23日、2月23日)
+    EmperorsBirthday,
+
+    /// 春分の日
+    VernalEquinoxDay,
+
+    /// 昭和の日
+    ShowaDay,
+
+    /// 憲法記念日
+    ConstitutionMemorialDay,
+
+    /// みどりの日 (4月29日、5月4日)
+    GreeneryDay,
+
+    /// こどもの日
+    ChildrensDay,
+
+    /// 海の日
+    MarineDay,
+
+    /// 山の日
+    MountainDay,
+
+    /// 敬老の日
+    RespectForTheAgedDay,
+
+    /// 秋分の日
+    AutumnalEquinoxDay,
+
+    /// 体育の日、スポーツの日
+    SportsDay,
+
+    /// 文化の日
+    CultureDay,
+
+    /// 勤労感謝の日
+    LaborThanksgivingDay,
+
+    /// 振替休日
+    SubstituteHoliday,
+
+    /// 国民の休日
+    CitizensHoliday,
+
+    /// 皇太子の結婚の儀 (1959年4月10日、1993年6月9日)
+    ImperialWedding,
+
+    /// 昭和天皇の大喪の礼 (1989年2月24日)
+    StateFuneral,
+
+    /// 天皇の即位の日 (2019年5月1日)
+    EnthronementDay,
+
+    /// 即位礼正殿の儀 (1990年11月12日、2019年10月22日)
+    EnthronementCeremony,
+}
+
+impl HolidayId {
+    /// 祝日の名称・種別・日付から ID を求めます。
+    ///
+    /// 対応する ID がない場合は `None` を返します。
+    pub fn from_holiday(holiday: &Holiday) -> Option<Self> {
+        match holiday.kind {
+            HolidayKind::Substitute => return Some(HolidayId::SubstituteHoliday),
+            HolidayKind::Citizens => return Some(HolidayId::CitizensHoliday),
+            _ => {}
+        }
+
+        match holiday.name.as_str() {
+            // 2019年の「休日（祝日扱い）」は即位の日と即位礼正殿の儀の2日です。
+            "休日（祝日扱い）" | "休日(祝日扱い)" => match holiday.date.month() {
+                5 => Some(HolidayId::EnthronementDay),
+                10 => Some(HolidayId::EnthronementCeremony),
+                _ => None,
+            },
+            name => Self::from_name(name),
+        }
+    }
+
+    /// 祝日の名称から ID を求めます。
+    ///
+    /// 「体育の日」「スポーツの日」のように、名称の変更前後のどちらからも同じ ID を返します。
+    /// 対応する ID がない場合は `None` を返します。
+    pub fn from_name(name: &str) -> Option<Self> {
+        let id = match name {
+            "元日" => HolidayId::NewYearsDay,
+            "成人の日" => HolidayId::ComingOfAgeDay,
+            "建国記念の日" => HolidayId::NationalFoundationDay,
+            "天皇誕生日" => HolidayId::EmperorsBirthday,
+            "春分の日" => HolidayId::VernalEquinoxDay,
+            "昭和の日" => HolidayId::ShowaDay,
+            "憲法記念日" => HolidayId::ConstitutionMemorialDay,
+            "みどりの日" => HolidayId::GreeneryDay,
+            "こどもの日" => HolidayId::ChildrensDay,
+            "海の日" => HolidayId::MarineDay,
+            "山の日" => HolidayId::MountainDay,
+            "敬老の日" => HolidayId::RespectForTheAgedDay,
+            "秋分の日" => HolidayId::AutumnalEquinoxDay,
+            "体育の日" | "スポーツの日" => HolidayId::SportsDay,
+            "文化の日" => HolidayId::CultureDay,
+            "勤労感謝の日" => HolidayId::LaborThanksgivingDay,
+            "振替休日" => HolidayId::SubstituteHoliday,
+            "国民の休日" => HolidayId::CitizensHoliday,
+            "結婚の儀" => HolidayId::ImperialWedding,
+            "大喪の礼" => HolidayId::StateFuneral,
+            "即位礼正殿の儀" => HolidayId::EnthronementCeremony,
+            _ => return None,
+        };
+
+        Some(id)
+    }
+}
+
+/// 祝日の情報です。
+///
+/// `Client::get_holiday_detail()` や `Client::list_holiday_details()` から取得できます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    /// 祝日の日付
+    pub date: NaiveDate,
+
+    /// CSV に記載されている祝日の名称
+    pub name: String,
+
+    /// 祝日の種別
+    pub kind: HolidayKind,
+
+    /// 振替休日の場合、振り替えられた元の祝日の日付
+    pub substitute_for: Option<NaiveDate>,
+}
+
+impl Holiday {
+    /// 祝日名を指定した言語で取得します。
+    ///
+    /// CSV 上で「休日」と記載されている日は、種別に応じて「振替休日」「国民の休日」として変換します。
+    /// 対応していない祝日名の場合は、CSV に記載されている名称をそのまま返します。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use jp_holidays_lib::{client::Client, locale::Locale};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2025, 7, 21).ok_or("存在しない日付です".to_string())?;
+    ///
+    ///     if let Some(holiday) = client.get_holiday_detail(date) {
+    ///         println!("{}", holiday.localized_name(Locale::English));
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn localized_name(&self, locale: crate::locale::Locale) -> &str {
+        crate::locale::localize_holiday(self, locale)
+    }
+
+    /// 名称の変更をまたいで祝日を識別するための ID を取得します。
+    ///
+    /// 詳細は `HolidayId::from_holiday()` を参照してください。
+    pub fn id(&self) -> Option<HolidayId> {
+        HolidayId::from_holiday(self)
+    }
+}
+
+/// CSV から得た日付と名称の一覧を種別付きの祝日に変換します。
+pub(crate) fn classify(
+    data: &std::collections::BTreeMap<NaiveDate, String>,
+) -> std::collections::BTreeMap<NaiveDate, Holiday> {
+    let substitutes = link_substitutes(data);
+
+    data.iter()
+        .map(|(date, name)| {
+            let (kind, substitute_for) = if SPECIAL_HOLIDAY_NAMES.contains(&name.as_str()) {
+                (HolidayKind::Special, None)
+            } else if name == GENERIC_HOLIDAY_NAME {
+                match substitutes.get(date) {
+                    Some(original) => (HolidayKind::Substitute, Some(*original)),
+                    None => (HolidayKind::Citizens, None),
+                }
+            } else {
+                (HolidayKind::National, None)
+            };
+
+            let holiday = Holiday {
+                date: *date,
+                name: name.clone(),
+                kind,
+                substitute_for,
+            };
+
+            (*date, holiday)
+        })
+        .collect()
+}
+
+/// 各年に施行されていた振替休日の規定に従い、振替休日とその元になった祝日を対応付けます。
+///
+/// - 1973年4月12日より前: 振替休日の制度はありません。
+/// - 2006年12月31日まで: 日曜日の祝日の翌日 (月曜日) が振替休日になります。
+/// - 2007年1月1日以降: 日曜日の祝日の後で、最も近い祝日でない日が振替休日になります。
+///
+/// 戻り値は振替休日の日付をキー、元の祝日の日付を値とするマップです。
+fn link_substitutes(
+    data: &std::collections::BTreeMap<NaiveDate, String>,
+) -> std::collections::BTreeMap<NaiveDate, NaiveDate> {
+    let (year, month, day) = SUBSTITUTE_RULE_START;
+    let rule_start = NaiveDate::from_ymd_opt(year, month, day).expect("valid date");
+    let (year, month, day) = SUBSTITUTE_CHAIN_RULE_START;
+    let chain_rule_start = NaiveDate::from_ymd_opt(year, month, day).expect("valid date");
+
+    data.iter()
+        .filter(|(date, name)| {
+            **date >= rule_start
+                && date.weekday() == chrono::Weekday::Sun
+                && name.as_str() != GENERIC_HOLIDAY_NAME
+        })
+        .filter_map(|(date, _)| {
+            let candidate = if *date < chain_rule_start {
+                date.succ_opt()?
+            } else {
+                let mut cursor = date.succ_opt()?;
+                while data
+                    .get(&cursor)
+                    .is_some_and(|name| name != GENERIC_HOLIDAY_NAME)
+                {
+                    cursor = cursor.succ_opt()?;
+                }
+                cursor
+            };
+
+            (data.get(&candidate)? == GENERIC_HOLIDAY_NAME).then_some((candidate, *date))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn data(entries: &[(NaiveDate, &str)]) -> std::collections::BTreeMap<NaiveDate, String> {
+        entries
+            .iter()
+            .map(|(date, name)| (*date, name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_classify_substitute_after_sunday() {
+        let data = data(&[(ymd(2023, 1, 1), "元日"), (ymd(2023, 1, 2), "休日")]);
+        let holidays = classify(&data);
+
+        assert_eq!(holidays[&ymd(2023, 1, 1)].kind, HolidayKind::National);
+        assert_eq!(holidays[&ymd(2023, 1, 2)].kind, HolidayKind::Substitute);
+        assert_eq!(
+            holidays[&ymd(2023, 1, 2)].substitute_for,
+            Some(ymd(2023, 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_classify_citizens_holiday() {
+        let data = data(&[
+            (ymd(2026, 9, 21), "敬老の日"),
+            (ymd(2026, 9, 22), "休日"),
+            (ymd(2026, 9, 23), "秋分の日"),
+        ]);
+        let holidays = classify(&data);
+
+        assert_eq!(holidays[&ymd(2026, 9, 22)].kind, HolidayKind::Citizens);
+        assert_eq!(holidays[&ymd(2026, 9, 22)].substitute_for, None);
+    }
+
+    #[test]
+    fn test_classify_special_holiday() {
+        let data = data(&[(ymd(2019, 5, 1), "休日（祝日扱い）")]);
+        let holidays = classify(&data);
+
+        assert_eq!(holidays[&ymd(2019, 5, 1)].kind, HolidayKind::Special);
+    }
+
+    #[test]
+    fn test_classify_substitute_chain_after_2007() {
+        let data = data(&[
+            (ymd(2008, 5, 3), "憲法記念日"),
+            (ymd(2008, 5, 4), "みどりの日"),
+            (ymd(2008, 5, 5), "こどもの日"),
+            (ymd(2008, 5, 6), "休日"),
+        ]);
+        let holidays = classify(&data);
+
+        assert_eq!(holidays[&ymd(2008, 5, 6)].kind, HolidayKind::Substitute);
+        assert_eq!(
+            holidays[&ymd(2008, 5, 6)].substitute_for,
+            Some(ymd(2008, 5, 4))
+        );
+    }
+
+    #[test]
+    fn test_classify_sunday_only_before_2007() {
+        // 1988年5月3日は火曜日のため、5月4日は国民の休日です。
+        // 一方で 1998年5月3日は日曜日のため、5月4日は振替休日です。
+        let data = data(&[
+            (ymd(1988, 5, 3), "憲法記念日"),
+            (ymd(1988, 5, 4), "休日"),
+            (ymd(1988, 5, 5), "こどもの日"),
+            (ymd(1998, 5, 3), "憲法記念日"),
+            (ymd(1998, 5, 4), "休日"),
+            (ymd(1998, 5, 5), "こどもの日"),
+        ]);
+        let holidays = classify(&data);
+
+        assert_eq!(holidays[&ymd(1988, 5, 4)].kind, HolidayKind::Citizens);
+        assert_eq!(holidays[&ymd(1998, 5, 4)].kind, HolidayKind::Substitute);
+        assert_eq!(
+            holidays[&ymd(1998, 5, 4)].substitute_for,
+            Some(ymd(1998, 5, 3))
+        );
+    }
+
+    #[test]
+    fn test_holiday_id_across_renames() {
+        let data = data(&[
+            (ymd(2019, 10, 14), "体育の日"),
+            (ymd(2020, 7, 24), "スポーツの日"),
+            (ymd(2019, 5, 1), "休日（祝日扱い）"),
+            (ymd(2019, 10, 22), "休日（祝日扱い）"),
+        ]);
+        let holidays = classify(&data);
+
+        assert_eq!(
+            holidays[&ymd(2019, 10, 14)].id(),
+            Some(HolidayId::SportsDay)
+        );
+        assert_eq!(holidays[&ymd(2020, 7, 24)].id(), Some(HolidayId::SportsDay));
+        assert_eq!(
+            holidays[&ymd(2019, 5, 1)].id(),
+            Some(HolidayId::EnthronementDay)
+        );
+        assert_eq!(
+            holidays[&ymd(2019, 10, 22)].id(),
+            Some(HolidayId::EnthronementCeremony)
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+/// 営業日の営業時間 (日本時間) と休日を組み合わせた、営業時間のカレンダーです。
+///
+/// 既定の営業時間はすべての曜日で 9:00〜18:00 で、休日 (土日祝日など) は `calendar` で判定します。
+/// 曜日ごとの営業時間と昼休みを設定できます。
+/// 時刻は任意のタイムゾーンの `chrono::DateTime` で渡せます。計算は日本時間で行い、結果は渡したタイムゾーンで返します。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::{Duration, NaiveTime, TimeZone, Utc};
+/// use jp_holidays_lib::{client::Client, hours::BusinessHours};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     let lunch_start = NaiveTime::from_hms_opt(12, 0, 0).ok_or("存在しない時刻です".to_string())?;
+///     let lunch_end = NaiveTime::from_hms_opt(13, 0, 0).ok_or("存在しない時刻です".to_string())?;
+///     let hours = BusinessHours::new(&client).with_lunch_break(lunch_start, lunch_end);
+///
+///     // 2026年4月30日 16:00 (日本時間) に受け付けた問い合わせの、8営業時間後の期限
+///     let received = Utc.with_ymd_and_hms(2026, 4, 30, 7, 0, 0).single().ok_or("存在しない時刻です".to_string())?;
+///     println!("期限は {}", hours.add(&received, Duration::hours(8))?);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BusinessHours<C> {
+    calendar: C,
+    /// 月曜日から順に、営業時間 (開始・終了)
+    hours: [Option<(NaiveTime, NaiveTime)>; 7],
+    lunch_break: Option<(NaiveTime, NaiveTime)>,
+}
+
+impl<C: crate::business::BusinessCalendar> BusinessHours<C> {
+    /// 休日の判定に使うカレンダーを渡して初期化します。営業時間は 9:00〜18:00 です。
+    pub fn new(calendar: C) -> Self {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).expect("9:00 は有効な時刻です");
+        let eighteen = NaiveTime::from_hms_opt(18, 0, 0).expect("18:00 は有効な時刻です");
+
+        Self {
+            calendar,
+            hours: [Some((nine, eighteen)); 7],
+            lunch_break: None,
+        }
+    }
+
+    /// すべての曜日の営業時間を設定します。
+    pub fn with_hours(mut self, open: NaiveTime, close: NaiveTime) -> Self {
+        self.hours = [Some((open, close)); 7];
+        self
+    }
+
+    /// 曜日を指定して営業時間を設定します。
+    pub fn with_hours_on(
+        mut self,
+        weekday: chrono::Weekday,
+        open: NaiveTime,
+        close: NaiveTime,
+    ) -> Self {
+        self.hours[weekday.num_days_from_monday() as usize] = Some((open, close));
+        self
+    }
+
+    /// 曜日を指定して、その曜日は営業しないように設定します。
+    pub fn closed_on(mut self, weekday: chrono::Weekday) -> Self {
+        self.hours[weekday.num_days_from_monday() as usize] = None;
+        self
+    }
+
+    /// 昼休みを設定します。
+    pub fn with_lunch_break(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.lunch_break = Some((start, end));
+        self
+    }
+
+    /// 休日の判定に使うカレンダーを取得します。
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    /// 日付を渡して、その日の営業時間帯 (日本時間) を取得します。休日の場合は空です。
+    ///
+    /// 昼休みがある場合は、昼休みの前後に分かれます。
+    pub fn intervals_on(&self, date: NaiveDate) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        if self.calendar.is_day_off(date) {
+            return Vec::new();
+        }
+
+        let Some((open, close)) = self.hours[date.weekday().num_days_from_monday() as usize] else {
+            return Vec::new();
+        };
+
+        let intervals = match self.lunch_break {
+            Some((start, end)) => vec![(open, close.min(start)), (open.max(end), close)],
+            None => vec![(open, close)],
+        };
+
+        intervals
+            .into_iter()
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| (date.and_time(start), date.and_time(end)))
+            .collect()
+    }
+
+    /// 時刻が営業時間内かどうかを判定します。
+    pub fn is_open<Tz: TimeZone>(&self, instant: &chrono::DateTime<Tz>) -> bool {
+        let local = crate::jst::to_japan_datetime(instant).naive_local();
+        self.intervals_on(local.date())
+            .iter()
+            .any(|(start, end)| *start <= local && local < *end)
+    }
+
+    /// 時刻に営業時間を加えた時刻を取得します。`duration` が負の場合は営業時間をさかのぼります。
+    ///
+    /// 営業時間外の時刻を渡した場合は、次の営業開始 (さかのぼる場合は直前の営業終了) から数えます。
+    /// 祝日データの範囲外に達した場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn add<Tz: TimeZone>(
+        &self,
+        instant: &chrono::DateTime<Tz>,
+        duration: chrono::Duration,
+    ) -> Result<chrono::DateTime<Tz>, crate::error::Error> {
+        if duration.is_zero() {
+            return Ok(instant.clone());
+        }
+
+        let cursor = crate::jst::to_japan_datetime(instant).naive_local();
+        let result = if duration > chrono::Duration::zero() {
+            self.add_forward(cursor, duration)?
+        } else {
+            self.add_backward(cursor, -duration)?
+        };
+
+        Ok(crate::jst::offset()
+            .from_local_datetime(&result)
+            .single()
+            .expect("固定オフセットの時刻は一意に定まります")
+            .with_timezone(&instant.timezone()))
+    }
+
+    /// 2つの時刻の間の営業時間を取得します。`end` が `start` より前の場合は負の値を返します。
+    ///
+    /// 期間が祝日データの範囲外にかかる場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn between<Tz1: TimeZone, Tz2: TimeZone>(
+        &self,
+        start: &chrono::DateTime<Tz1>,
+        end: &chrono::DateTime<Tz2>,
+    ) -> Result<chrono::Duration, crate::error::Error> {
+        let start = crate::jst::to_japan_datetime(start).naive_local();
+        let end = crate::jst::to_japan_datetime(end).naive_local();
+
+        if end < start {
+            return self.between_naive(end, start).map(|duration| -duration);
+        }
+        self.between_naive(start, end)
+    }
+
+    fn between_naive(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<chrono::Duration, crate::error::Error> {
+        crate::business::ensure_covered(&self.calendar, start.date())?;
+        crate::business::ensure_covered(&self.calendar, end.date())?;
+
+        Ok(start
+            .date()
+            .iter_days()
+            .take_while(|date| *date <= end.date())
+            .flat_map(|date| self.intervals_on(date))
+            .map(|(open, close)| {
+                let from = open.max(start);
+                let to = close.min(end);
+                if from < to {
+                    to - from
+                } else {
+                    chrono::Duration::zero()
+                }
+            })
+            .fold(chrono::Duration::zero(), |total, duration| total + duration))
+    }
+
+    fn add_forward(
+        &self,
+        cursor: NaiveDateTime,
+        mut remaining: chrono::Duration,
+    ) -> Result<NaiveDateTime, crate::error::Error> {
+        let mut date = cursor.date();
+        loop {
+            crate::business::ensure_covered(&self.calendar, date)?;
+
+            for (open, close) in self.intervals_on(date) {
+                if close <= cursor {
+                    continue;
+                }
+                let from = open.max(cursor);
+                let available = close - from;
+                if remaining <= available {
+                    return Ok(from + remaining);
+                }
+                remaining -= available;
+            }
+
+            date = date.succ_opt().ok_or_else(|| {
+                crate::error::Error::OutOfRange(format!("{} の翌日がありません", date))
+            })?;
+        }
+    }
+
+    fn add_backward(
+        &self,
+        cursor: NaiveDateTime,
+        mut remaining: chrono::Duration,
+    ) -> Result<NaiveDateTime, crate::error::Error> {
+        let mut date = cursor.date();
+        loop {
+            crate::business::ensure_covered(&self.calendar, date)?;
+
+            for (open, close) in self.intervals_on(date).into_iter().rev() {
+                if cursor <= open {
+                    continue;
+                }
+                let to = close.min(cursor);
+                let available = to - open;
+                if remaining <= available {
+                    return Ok(to - remaining);
+                }
+                remaining -= available;
+            }
+
+            date = date.pred_opt().ok_or_else(|| {
+                crate::error::Error::OutOfRange(format!("{} の前日がありません", date))
+            })?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jst_at(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+    ) -> chrono::DateTime<chrono::FixedOffset> {
+        crate::jst::offset()
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_add_over_golden_week() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let hours = BusinessHours::new(&client).with_lunch_break(time(12, 0), time(13, 0));
+
+        // 4月30日 16:00 から 12時間: 30日に2時間、5月1日に8時間、7日に2時間
+        assert_eq!(
+            hours
+                .add(&jst_at(2026, 4, 30, 16, 0), chrono::Duration::hours(12))
+                .unwrap(),
+            jst_at(2026, 5, 7, 11, 0)
+        );
+        assert_eq!(
+            hours
+                .add(&jst_at(2026, 5, 7, 11, 0), chrono::Duration::hours(-12))
+                .unwrap(),
+            jst_at(2026, 4, 30, 16, 0)
+        );
+        // 昼休みをまたぐ
+        assert_eq!(
+            hours
+                .add(&jst_at(2026, 5, 7, 11, 30), chrono::Duration::hours(1))
+                .unwrap(),
+            jst_at(2026, 5, 7, 13, 30)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_with_other_time_zone() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let hours = BusinessHours::new(&client);
+
+        // 2026年5月1日 23:00 UTC は 5月2日 8:00 JST (土曜日)
+        let instant = chrono::Utc.with_ymd_and_hms(2026, 5, 1, 23, 0, 0).unwrap();
+        let due = hours.add(&instant, chrono::Duration::hours(1)).unwrap();
+        assert_eq!(
+            due,
+            chrono::Utc.with_ymd_and_hms(2026, 5, 7, 1, 0, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_is_open_and_between() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let hours = BusinessHours::new(&client)
+            .with_lunch_break(time(12, 0), time(13, 0))
+            .with_hours_on(chrono::Weekday::Fri, time(9, 0), time(15, 0));
+
+        assert!(hours.is_open(&jst_at(2026, 5, 7, 9, 0)));
+        assert!(!hours.is_open(&jst_at(2026, 5, 7, 12, 30)));
+        assert!(!hours.is_open(&jst_at(2026, 5, 7, 18, 0)));
+        assert!(!hours.is_open(&jst_at(2026, 5, 4, 10, 0)));
+
+        // 5月7日 (木) 17:00〜18:00 と 8日 (金) 9:00〜12:00, 13:00〜15:00
+        let start = jst_at(2026, 5, 7, 17, 0);
+        let end = jst_at(2026, 5, 11, 9, 0);
+        assert_eq!(
+            hours.between(&start, &end).unwrap(),
+            chrono::Duration::hours(6)
+        );
+        assert_eq!(
+            hours.between(&end, &start).unwrap(),
+            chrono::Duration::hours(-6)
+        );
+    }
+}
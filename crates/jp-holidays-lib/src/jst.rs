@@ -0,0 +1,76 @@
+//! 日本標準時 (JST, UTC+9) への変換を行います。
+//!
+//! 祝日は日本の暦日で決まるため、UTC などで表された時刻は日本時間の日付に変換してから判定する必要があります。
+//! 例えば 2026年5月4日 0:30 (日本時間) は UTC では5月3日 15:30 ですが、日本では5月4日 (みどりの日) です。
+//!
+//! ## 使用例
+//!
+//! ```
+//! use chrono::{TimeZone, Utc};
+//! use jp_holidays_lib::jst;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let instant = Utc
+//!         .with_ymd_and_hms(2026, 5, 3, 15, 30, 0)
+//!         .single()
+//!         .ok_or("存在しない時刻です".to_string())?;
+//!
+//!     // 2026-05-04
+//!     println!("日本時間の日付は {}", jst::to_japan_date(&instant));
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! 「今日」を基準にする処理は `crate::clock::Clock` を通して行います。
+//! 時計を固定したい場合は `crate::client::Client::with_clock()` に `crate::clock::FixedClock` を渡します。
+
+use chrono::{NaiveDate, TimeZone};
+
+/// 日本標準時 (UTC+9) のオフセットを取得します。日本では夏時間を実施していないため、常に一定です。
+pub fn offset() -> chrono::FixedOffset {
+    chrono::FixedOffset::east_opt(9 * 60 * 60).expect("UTC+9 は有効なオフセットです")
+}
+
+/// 任意のタイムゾーンの時刻を、日本時間の時刻に変換します。
+pub fn to_japan_datetime<Tz: TimeZone>(
+    instant: &chrono::DateTime<Tz>,
+) -> chrono::DateTime<chrono::FixedOffset> {
+    instant.with_timezone(&offset())
+}
+
+/// 任意のタイムゾーンの時刻を、日本時間の日付に変換します。
+pub fn to_japan_date<Tz: TimeZone>(instant: &chrono::DateTime<Tz>) -> NaiveDate {
+    to_japan_datetime(instant).date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_japan_date() {
+        // 日本時間の0:30は、UTCでは前日の15:30
+        let instant = chrono::Utc.with_ymd_and_hms(2026, 5, 3, 15, 30, 0).unwrap();
+        assert_eq!(
+            to_japan_date(&instant),
+            NaiveDate::from_ymd_opt(2026, 5, 4).unwrap()
+        );
+
+        let instant = chrono::Utc
+            .with_ymd_and_hms(2026, 5, 3, 14, 59, 59)
+            .unwrap();
+        assert_eq!(
+            to_japan_date(&instant),
+            NaiveDate::from_ymd_opt(2026, 5, 3).unwrap()
+        );
+
+        // ニューヨーク (UTC-4) の5月3日 12:00 は日本時間の5月4日 1:00
+        let new_york = chrono::FixedOffset::west_opt(4 * 60 * 60).unwrap();
+        let instant = new_york.with_ymd_and_hms(2026, 5, 3, 12, 0, 0).unwrap();
+        assert_eq!(
+            to_japan_date(&instant),
+            NaiveDate::from_ymd_opt(2026, 5, 4).unwrap()
+        );
+    }
+}
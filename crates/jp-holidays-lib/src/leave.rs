@@ -0,0 +1,213 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 休暇を取得する日の提案です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaveSuggestion {
+    /// 休暇を取得する日 (営業日)
+    pub leave_days: Vec<NaiveDate>,
+
+    /// 休暇と休日を合わせた連休の初日
+    pub start: NaiveDate,
+
+    /// 休暇と休日を合わせた連休の最終日
+    pub end: NaiveDate,
+}
+
+impl LeaveSuggestion {
+    /// 休暇と休日を合わせた連休の日数を取得します。
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+}
+
+/// 限られた休暇の日数で、できるだけ長い連休になる取得日を提案します。
+///
+/// 年と休暇の日数を渡すと、その年の営業日に休暇を割り当てた場合の連休を、長い順に提案します。
+/// 連休の長さが同じ場合は、休暇の日数が少ない順、日付順に並べます。
+/// 休日の判定には任意の `crate::business::BusinessCalendar` を使用できるため、
+/// 会社独自の休業日を重ねた `crate::overlay::OverlayCalendar` でも利用できます。
+///
+/// ## 使用例
+///
+/// ```
+/// use jp_holidays_lib::{client::Client, leave::LeavePlanner};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     // 有給休暇2日で取れる長い連休
+///     for suggestion in LeavePlanner::new(&client, 2).suggest(2026)? {
+///         println!(
+///             "{} 〜 {} ({} 連休) | 休暇: {:?}",
+///             suggestion.start,
+///             suggestion.end,
+///             suggestion.days(),
+///             suggestion.leave_days
+///         );
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct LeavePlanner<C> {
+    calendar: C,
+    budget: usize,
+    limit: usize,
+}
+
+impl<C: crate::business::BusinessCalendar> LeavePlanner<C> {
+    /// 休日の判定に使うカレンダーと、休暇の日数を渡して初期化します。提案は最大10件です。
+    pub fn new(calendar: C, budget: usize) -> Self {
+        Self {
+            calendar,
+            budget,
+            limit: 10,
+        }
+    }
+
+    /// 提案の最大件数を設定します。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// 年を渡して、その年の休暇の取得日を提案します。
+    ///
+    /// 休暇はその年の営業日にのみ割り当てます。連休は前後の年の休日にまたがる場合があります。
+    /// 年の一部でも祝日データの範囲外の場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn suggest(&self, year: i32) -> Result<Vec<LeaveSuggestion>, crate::error::Error> {
+        let (first, _) = crate::business::month_bounds(year, 1)?;
+        let (_, last) = crate::business::month_bounds(year, 12)?;
+        crate::business::ensure_covered(&self.calendar, first)?;
+        crate::business::ensure_covered(&self.calendar, last)?;
+
+        // 年をまたぐ連休を見つけられるよう、前後の休日も含めます。
+        let coverage = self.calendar.coverage();
+        let is_day_off =
+            |date: &NaiveDate| coverage.contains(date) && self.calendar.is_day_off(*date);
+        let mut start = first;
+        while let Some(previous) = start.pred_opt().filter(is_day_off) {
+            start = previous;
+        }
+        let mut end = last;
+        while let Some(next) = end.succ_opt().filter(is_day_off) {
+            end = next;
+        }
+
+        let days: Vec<(NaiveDate, bool)> = start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .map(|date| (date, self.calendar.is_day_off(date)))
+            .collect();
+
+        // 各日を連休の初日とした場合に、休暇を使い切るまでできるだけ先へ延ばします。
+        let mut suggestions = Vec::new();
+        let mut right = 0;
+        let mut leave = 0;
+        for left in 0..days.len() {
+            if right < left {
+                right = left;
+                leave = 0;
+            }
+            while right < days.len() && (days[right].1 || leave < self.budget) {
+                if !days[right].1 {
+                    leave += 1;
+                }
+                right += 1;
+            }
+
+            // 前日が休日の場合は、前日から始めたほうが長い連休になります。
+            let extendable = left > 0 && days[left - 1].1;
+            if !extendable && right > left {
+                let window = &days[left..right];
+                let leave_days: Vec<NaiveDate> = window
+                    .iter()
+                    .filter(|(_, day_off)| !day_off)
+                    .map(|(date, _)| *date)
+                    .collect();
+
+                if !leave_days.is_empty() && leave_days.iter().all(|date| date.year() == year) {
+                    suggestions.push(LeaveSuggestion {
+                        leave_days,
+                        start: window[0].0,
+                        end: window[window.len() - 1].0,
+                    });
+                }
+            }
+
+            if right > left && !days[left].1 {
+                leave -= 1;
+            }
+        }
+
+        suggestions.sort_by(|a, b| {
+            b.days()
+                .cmp(&a.days())
+                .then(a.leave_days.len().cmp(&b.leave_days.len()))
+                .then(a.start.cmp(&b.start))
+        });
+        suggestions.truncate(self.limit);
+
+        Ok(suggestions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_suggest() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let suggestions = LeavePlanner::new(&client, 2).suggest(2026).unwrap();
+
+        // ゴールデンウィーク (5月2日〜6日) とシルバーウィーク (9月19日〜23日) の後に2日休むと9連休
+        assert_eq!(
+            suggestions[0],
+            LeaveSuggestion {
+                leave_days: vec![ymd(2026, 5, 7), ymd(2026, 5, 8)],
+                start: ymd(2026, 5, 2),
+                end: ymd(2026, 5, 10),
+            }
+        );
+        assert_eq!(
+            suggestions[1],
+            LeaveSuggestion {
+                leave_days: vec![ymd(2026, 9, 24), ymd(2026, 9, 25)],
+                start: ymd(2026, 9, 19),
+                end: ymd(2026, 9, 27),
+            }
+        );
+
+        // ゴールデンウィークの前に2日休むと8連休
+        assert!(suggestions.iter().any(|suggestion| {
+            suggestion.leave_days == vec![ymd(2026, 4, 30), ymd(2026, 5, 1)]
+                && suggestion.days() == 8
+        }));
+        assert!(suggestions.len() <= 10);
+        assert!(
+            suggestions
+                .windows(2)
+                .all(|pair| pair[0].days() >= pair[1].days())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_suggest_with_overlay() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::overlay::OverlayCalendar::new(&client)
+            .with_closures_between(ymd(2026, 8, 12)..=ymd(2026, 8, 14), "お盆休み");
+
+        let suggestions = LeavePlanner::new(&calendar, 1).suggest(2026).unwrap();
+
+        // 8月8日 (土) 〜 16日 (日): 山の日 (11日) とお盆休みの間の10日を休むと9連休
+        assert_eq!(suggestions[0].leave_days, vec![ymd(2026, 8, 10)]);
+        assert_eq!(suggestions[0].days(), 9);
+    }
+}
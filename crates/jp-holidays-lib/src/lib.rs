@@ -65,6 +65,52 @@
 //! - `is_day_off()`: `chrono::NaiveDate` を渡して休日かどうかを判定します。
 //! - `is_day_off_ymd.()`: 年月日を渡して休日かどうかを判定します。
 //! - `list_holidays()`: 公開されている祝日をすべて取得します (`BTreeMap<NaiveDate, String>`)
+//! - `get_holiday_detail()`: `chrono::NaiveDate` を渡して種別付きの祝日を取得します。
+//! - `get_holiday_detail_ymd()`: 年月日を渡して種別付きの祝日を取得します。
+//! - `list_holiday_details()`: 公開されている祝日を種別付きですべて取得します (`BTreeMap<NaiveDate, Holiday>`)
+//! - `get_substituted_holiday()`: 振替休日の日付を渡して、振り替えられた元の祝日を取得します。
+//! - `get_substitute_holiday()`: 祝日の日付を渡して、その祝日の振替休日を取得します。
+//! - `list_substitute_holidays()`: 振替休日と元の祝日の組をすべて取得します。
+//! - `get_holiday_display_name()`: 振替元を含めた表示用の祝日名を取得します。
+//! - `get_holiday_localized()`: `chrono::NaiveDate` と言語を渡して祝日名を取得します。
+//! - `get_holiday_localized_ymd()`: 年月日と言語を渡して祝日名を取得します。
+//! - `get_holiday_id()`: `chrono::NaiveDate` を渡して祝日の ID を取得します。
+//! - `list_holidays_by_id()`: `HolidayId` を渡して、名称の変更をまたいでその祝日をすべて取得します。
+//! - `holidays_in_year()`: 年を渡して、その年の祝日を取得します。
+//! - `holidays_in_month()`: 年月を渡して、その月の祝日を取得します。
+//! - `holidays_between()`: 期間を渡して、その期間の祝日を取得します。
+//! - `next_holiday()`: `chrono::NaiveDate` を渡して、その日より後で最も近い祝日を取得します。
+//! - `previous_holiday()`: `chrono::NaiveDate` を渡して、その日より前で最も近い祝日を取得します。
+//! - `get_holiday_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日の祝日を取得します。
+//! - `get_holiday_detail_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日の種別付きの祝日を取得します。
+//! - `is_holiday_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日が祝日かどうかを判定します。
+//! - `is_day_off_at()`: 任意のタイムゾーンの時刻を渡して、日本時間のその日が休日かどうかを判定します。
+//! - `with_clock()`: 「今日」を基準にするメソッドで使用する時計を設定します。
+//! - `today()`: 設定されている時計から、日本時間の今日の日付を取得します。
+//! - `is_today_holiday()`: 日本時間の今日が祝日かどうかを判定します。
+//! - `is_today_day_off()`: 日本時間の今日が休日かどうかを判定します。
+//! - `next_holiday_from_today()`: 日本時間の今日より後で最も近い祝日を取得します。
+//! - `days_until_next_holiday()`: 日本時間の今日から次の祝日までの日数を取得します。
+//! - `coverage()`: 祝日データに含まれる年の範囲を取得します。
+//! - `next_business_day()`: 翌営業日を取得します。
+//! - `previous_business_day()`: 前営業日を取得します。
+//! - `add_business_days()`: N 営業日後 (負の場合は前) の日付を取得します。
+//! - `business_days_between()`: 期間内の営業日の日数を数えます。
+//! - `adjust()`: 営業日調整規則に従って、休日を営業日に調整します。
+//! - `business_days_of_month()`: 年月を渡して、その月の営業日をすべて取得します。
+//! - `count_business_days_in_month()`: 年月を渡して、その月の営業日の日数を数えます。
+//! - `nth_business_day_of_month()`: 年月を渡して、その月の第 N 営業日を取得します。
+//! - `last_business_day_of_month()`: 年月を渡して、その月の最終営業日を取得します。
+//! - `business_day_index_in_month()`: `chrono::NaiveDate` を渡して、その日が月の第何営業日かを取得します。
+//! - `adjusted_day_of_month()`: 年月と日を渡して、営業日調整規則に従って調整した日付を取得します。
+//! - `day_off_runs()`: 期間を渡して、その期間と重なる連休をすべて取得します。
+//! - `break_containing()`: `chrono::NaiveDate` を渡して、その日を含む連休を取得します。
+//! - `golden_week()`: 年を渡して、その年のゴールデンウィークを取得します。
+//! - `silver_week()`: 年を渡して、その年のシルバーウィークを取得します。
+//! - `bridge_days()`: 期間を渡して、休日に挟まれた営業日を取得します。
+//! - `explain()`: `chrono::NaiveDate` を渡して、その日が休日になる理由をすべて取得します。
+//! - `classify_days()`: 期間を渡して、期間内の日付を種別を判定しながら順に返すイテレーターを取得します。
+//! - `format_japanese()`: `chrono::NaiveDate` を渡して、和暦・曜日・祝日名を組み合わせた文字列 (`令和8年5月3日(日・憲法記念日)`) を取得します。
 //!
 //! ## キャッシュの利用
 //!
@@ -129,7 +175,31 @@
 //! }
 //! ```
 
+pub mod administrative;
+pub mod bank;
+pub mod bridge;
+pub mod business;
+pub mod calendar;
 pub mod client;
+pub mod clock;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod convention;
+pub mod days;
+pub mod deadline;
+pub mod era;
 pub mod error;
+pub mod exchange;
+pub mod explain;
+pub mod gotobi;
+pub mod holiday;
+pub mod hours;
+pub mod jst;
+pub mod leave;
+pub mod locale;
+pub mod overlay;
 pub(crate) mod repository;
+pub mod rule;
+pub mod runs;
+pub mod schedule;
 pub(crate) mod service;
@@ -0,0 +1,211 @@
+/// 祝日名の表示言語です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// 日本語 (原則として CSV に記載されている名称)
+    #[default]
+    Japanese,
+
+    /// 英語 (内閣府の英語表記に準拠)
+    English,
+
+    /// ひらがなの読み
+    Hiragana,
+
+    /// ローマ字 (ヘボン式)
+    Romaji,
+}
+
+/// 祝日名ごとの各言語の表記です。(日本語, 英語, ひらがな, ローマ字)
+const NAMES: [(&str, &str, &str, &str); 22] = [
+    ("元日", "New Year's Day", "がんじつ", "Ganjitsu"),
+    (
+        "成人の日",
+        "Coming of Age Day",
+        "せいじんのひ",
+        "Seijin no Hi",
+    ),
+    (
+        "建国記念の日",
+        "National Foundation Day",
+        "けんこくきねんのひ",
+        "Kenkoku Kinen no Hi",
+    ),
+    (
+        "天皇誕生日",
+        "The Emperor's Birthday",
+        "てんのうたんじょうび",
+        "Tennō Tanjōbi",
+    ),
+    (
+        "春分の日",
+        "Vernal Equinox Day",
+        "しゅんぶんのひ",
+        "Shunbun no Hi",
+    ),
+    ("昭和の日", "Showa Day", "しょうわのひ", "Shōwa no Hi"),
+    (
+        "憲法記念日",
+        "Constitution Memorial Day",
+        "けんぽうきねんび",
+        "Kenpō Kinenbi",
+    ),
+    ("みどりの日", "Greenery Day", "みどりのひ", "Midori no Hi"),
+    ("こどもの日", "Children's Day", "こどものひ", "Kodomo no Hi"),
+    ("海の日", "Marine Day", "うみのひ", "Umi no Hi"),
+    ("山の日", "Mountain Day", "やまのひ", "Yama no Hi"),
+    (
+        "敬老の日",
+        "Respect for the Aged Day",
+        "けいろうのひ",
+        "Keirō no Hi",
+    ),
+    (
+        "秋分の日",
+        "Autumnal Equinox Day",
+        "しゅうぶんのひ",
+        "Shūbun no Hi",
+    ),
+    (
+        "体育の日",
+        "Health and Sports Day",
+        "たいいくのひ",
+        "Taiiku no Hi",
+    ),
+    (
+        "スポーツの日",
+        "Sports Day",
+        "すぽーつのひ",
+        "Supōtsu no Hi",
+    ),
+    ("文化の日", "Culture Day", "ぶんかのひ", "Bunka no Hi"),
+    (
+        "勤労感謝の日",
+        "Labor Thanksgiving Day",
+        "きんろうかんしゃのひ",
+        "Kinrō Kansha no Hi",
+    ),
+    ("休日", "Holiday", "きゅうじつ", "Kyūjitsu"),
+    (
+        "休日（祝日扱い）",
+        "Holiday (treated as a national holiday)",
+        "きゅうじつ（しゅくじつあつかい）",
+        "Kyūjitsu (Shukujitsu Atsukai)",
+    ),
+    (
+        "結婚の儀",
+        "The Rite of Wedding of the Crown Prince",
+        "けっこんのぎ",
+        "Kekkon no Gi",
+    ),
+    (
+        "大喪の礼",
+        "The Funeral Ceremony of Emperor Showa",
+        "たいそうのれい",
+        "Taisō no Rei",
+    ),
+    (
+        "即位礼正殿の儀",
+        "The Ceremony of the Enthronement",
+        "そくいれいせいでんのぎ",
+        "Sokuirei Seiden no Gi",
+    ),
+];
+
+/// 振替休日の各言語の表記です。
+const SUBSTITUTE_NAME: (&str, &str, &str, &str) = (
+    "振替休日",
+    "Substitute Holiday",
+    "ふりかえきゅうじつ",
+    "Furikae Kyūjitsu",
+);
+
+/// 国民の休日の各言語の表記です。
+const CITIZENS_NAME: (&str, &str, &str, &str) = (
+    "国民の休日",
+    "Citizens' Holiday",
+    "こくみんのきゅうじつ",
+    "Kokumin no Kyūjitsu",
+);
+
+impl Locale {
+    fn select(
+        self,
+        names: (&'static str, &'static str, &'static str, &'static str),
+    ) -> &'static str {
+        let (japanese, english, hiragana, romaji) = names;
+        match self {
+            Locale::Japanese => japanese,
+            Locale::English => english,
+            Locale::Hiragana => hiragana,
+            Locale::Romaji => romaji,
+        }
+    }
+}
+
+/// 祝日名を指定した言語に変換します。
+///
+/// 対応していない祝日名の場合は、渡した名称をそのまま返します。
+///
+/// ## 使用例
+///
+/// ```
+/// use jp_holidays_lib::locale::{Locale, localize};
+///
+/// assert_eq!(localize("海の日", Locale::English), "Marine Day");
+/// assert_eq!(localize("海の日", Locale::Hiragana), "うみのひ");
+/// assert_eq!(localize("海の日", Locale::Romaji), "Umi no Hi");
+/// assert_eq!(localize("創立記念日", Locale::English), "創立記念日");
+/// ```
+pub fn localize(name: &str, locale: Locale) -> &str {
+    if locale == Locale::Japanese {
+        return name;
+    }
+
+    NAMES
+        .iter()
+        .find(|(japanese, _, _, _)| *japanese == name)
+        .map(|names| locale.select(*names))
+        .unwrap_or(name)
+}
+
+/// 種別を考慮して祝日名を指定した言語に変換します。
+pub(crate) fn localize_holiday(holiday: &crate::holiday::Holiday, locale: Locale) -> &str {
+    match holiday.kind {
+        crate::holiday::HolidayKind::Substitute => locale.select(SUBSTITUTE_NAME),
+        crate::holiday::HolidayKind::Citizens => locale.select(CITIZENS_NAME),
+        _ => localize(&holiday.name, locale),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localize_known_name() {
+        assert_eq!(localize("スポーツの日", Locale::English), "Sports Day");
+        assert_eq!(localize("敬老の日", Locale::Romaji), "Keirō no Hi");
+        assert_eq!(localize("元日", Locale::Japanese), "元日");
+    }
+
+    #[test]
+    fn test_localize_unknown_name_falls_back() {
+        assert_eq!(localize("創立記念日", Locale::Hiragana), "創立記念日");
+    }
+
+    #[test]
+    fn test_localized_name_by_kind() {
+        let holiday = crate::holiday::Holiday {
+            date: chrono::NaiveDate::from_ymd_opt(2024, 2, 12).unwrap(),
+            name: "休日".to_string(),
+            kind: crate::holiday::HolidayKind::Substitute,
+            substitute_for: chrono::NaiveDate::from_ymd_opt(2024, 2, 11),
+        };
+
+        assert_eq!(
+            holiday.localized_name(Locale::English),
+            "Substitute Holiday"
+        );
+        assert_eq!(holiday.localized_name(Locale::Japanese), "振替休日");
+    }
+}
@@ -0,0 +1,255 @@
+use chrono::NaiveDate;
+
+/// 元のカレンダーに対して、特定の日の休日・営業日を上書きする設定です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Override {
+    /// 会社独自の休業日 (年末年始、お盆、創立記念日など)
+    Closure {
+        /// 休業日の名称
+        label: String,
+    },
+
+    /// 休日であっても営業する日 (出勤日)
+    WorkingDay {
+        /// 出勤日の名称
+        label: String,
+    },
+}
+
+impl Override {
+    /// 上書きの名称を取得します。
+    pub fn label(&self) -> &str {
+        match self {
+            Override::Closure { label } | Override::WorkingDay { label } => label,
+        }
+    }
+}
+
+/// 国民の祝日などのカレンダーに、会社独自の休業日と出勤日を重ねたカレンダーです。
+///
+/// 同じ日に複数の設定を行った場合は、後から設定したものが優先されます。
+/// 休日の判定と営業日の計算は `crate::business::BusinessCalendar` のメソッドで行います。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use jp_holidays_lib::{business::BusinessCalendar, client::Client, overlay::OverlayCalendar};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     let start = NaiveDate::from_ymd_opt(2026, 8, 13).ok_or("存在しない日付です".to_string())?;
+///     let end = NaiveDate::from_ymd_opt(2026, 8, 16).ok_or("存在しない日付です".to_string())?;
+///     let founding_day = NaiveDate::from_ymd_opt(2026, 6, 1).ok_or("存在しない日付です".to_string())?;
+///     let saturday = NaiveDate::from_ymd_opt(2026, 6, 6).ok_or("存在しない日付です".to_string())?;
+///
+///     let calendar = OverlayCalendar::new(&client)
+///         .with_closures_between(start..=end, "お盆休み")
+///         .with_closure(founding_day, "創立記念日")
+///         .with_working_day(saturday, "振替出勤日");
+///
+///     println!("2026年 8月 13日 は{}", if calendar.is_day_off(start) { "休日です" } else { "営業日です" });
+///     println!("2026年 6月 6日 は{}", if calendar.is_day_off(saturday) { "休日です" } else { "営業日です" });
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OverlayCalendar<C> {
+    base: C,
+    overrides: std::collections::BTreeMap<NaiveDate, Override>,
+}
+
+impl<C: crate::business::BusinessCalendar> OverlayCalendar<C> {
+    /// 元になるカレンダーを渡して初期化します。
+    pub fn new(base: C) -> Self {
+        Self {
+            base,
+            overrides: std::collections::BTreeMap::new(),
+        }
+    }
+
+    /// 休業日を追加します。
+    pub fn with_closure(mut self, date: NaiveDate, label: impl Into<String>) -> Self {
+        self.overrides.insert(
+            date,
+            Override::Closure {
+                label: label.into(),
+            },
+        );
+        self
+    }
+
+    /// 期間内のすべての日を休業日として追加します。
+    pub fn with_closures_between(
+        mut self,
+        period: std::ops::RangeInclusive<NaiveDate>,
+        label: impl Into<String>,
+    ) -> Self {
+        let label = label.into();
+        for date in period
+            .start()
+            .iter_days()
+            .take_while(|date| date <= period.end())
+        {
+            self.overrides.insert(
+                date,
+                Override::Closure {
+                    label: label.clone(),
+                },
+            );
+        }
+        self
+    }
+
+    /// 休日であっても営業する日を追加します。
+    pub fn with_working_day(mut self, date: NaiveDate, label: impl Into<String>) -> Self {
+        self.overrides.insert(
+            date,
+            Override::WorkingDay {
+                label: label.into(),
+            },
+        );
+        self
+    }
+
+    /// 元になるカレンダーを取得します。
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    /// 指定した日の上書き設定を取得します。
+    pub fn get_override(&self, date: NaiveDate) -> Option<&Override> {
+        self.overrides.get(&date)
+    }
+
+    /// すべての上書き設定を取得します。
+    pub fn list_overrides(&self) -> &std::collections::BTreeMap<NaiveDate, Override> {
+        &self.overrides
+    }
+}
+
+impl<C: crate::business::BusinessCalendar> crate::business::BusinessCalendar
+    for OverlayCalendar<C>
+{
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        match self.overrides.get(&date) {
+            Some(Override::Closure { .. }) => true,
+            Some(Override::WorkingDay { .. }) => false,
+            None => self.base.is_day_off(date),
+        }
+    }
+
+    fn coverage(&self) -> std::ops::RangeInclusive<NaiveDate> {
+        self.base.coverage()
+    }
+
+    fn get_holiday_detail(&self, date: NaiveDate) -> Option<&crate::holiday::Holiday> {
+        self.base.get_holiday_detail(date)
+    }
+
+    fn explain(&self, date: NaiveDate) -> Vec<crate::explain::Reason> {
+        let mut reasons = match self.overrides.get(&date) {
+            Some(Override::Closure { label }) => vec![crate::explain::Reason::Closure {
+                label: label.clone(),
+            }],
+            Some(Override::WorkingDay { label }) => vec![crate::explain::Reason::WorkingDay {
+                label: label.clone(),
+            }],
+            None => Vec::new(),
+        };
+        reasons.extend(self.base.explain(date));
+        reasons
+    }
+
+    fn count_days_off(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end <= start {
+            return 0;
+        }
+
+        let adjustment: i64 = self
+            .overrides
+            .range(start..end)
+            .map(|(date, entry)| match (entry, self.base.is_day_off(*date)) {
+                (Override::Closure { .. }, false) => 1,
+                (Override::WorkingDay { .. }, true) => -1,
+                _ => 0,
+            })
+            .sum();
+
+        self.base.count_days_off(start, end) + adjustment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::BusinessCalendar;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_closures_and_working_days() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = OverlayCalendar::new(&client)
+            .with_closures_between(ymd(2025, 12, 29)..=ymd(2026, 1, 3), "年末年始")
+            .with_working_day(ymd(2026, 1, 3), "棚卸し");
+
+        assert!(calendar.is_day_off(ymd(2025, 12, 29)));
+        assert!(calendar.is_day_off(ymd(2026, 1, 2)));
+        assert!(!calendar.is_day_off(ymd(2026, 1, 3)));
+        assert_eq!(
+            calendar.next_business_day(ymd(2025, 12, 26)).unwrap(),
+            ymd(2026, 1, 3)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_explain_working_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = OverlayCalendar::new(&client)
+            .with_working_day(ymd(2026, 6, 6), "振替出勤日")
+            .with_working_day(ymd(2026, 6, 8), "通常の平日");
+
+        // 土曜日の出勤日は、週休日を打ち消したことを返します。
+        assert!(!calendar.is_day_off(ymd(2026, 6, 6)));
+        assert_eq!(
+            calendar.explain(ymd(2026, 6, 6)),
+            vec![
+                crate::explain::Reason::WorkingDay {
+                    label: "振替出勤日".to_string()
+                },
+                crate::explain::Reason::Weekend(chrono::Weekday::Sat),
+            ]
+        );
+
+        // 打ち消す休日がない場合も、出勤日の設定を返します。
+        assert_eq!(
+            calendar.explain(ymd(2026, 6, 8)),
+            vec![crate::explain::Reason::WorkingDay {
+                label: "通常の平日".to_string()
+            }]
+        );
+        assert!(calendar.explain(ymd(2026, 6, 9)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_count_days_off_matches_day_by_day() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = OverlayCalendar::new(&client)
+            .with_closures_between(ymd(2026, 8, 13)..=ymd(2026, 8, 16), "お盆休み")
+            .with_closure(ymd(2026, 6, 1), "創立記念日")
+            .with_working_day(ymd(2026, 6, 6), "振替出勤日")
+            .with_working_day(ymd(2026, 6, 8), "通常の平日");
+
+        crate::business::assert_count_days_off_matches_day_by_day(
+            &calendar,
+            ymd(2026, 1, 1),
+            ymd(2026, 12, 31),
+        );
+    }
+}
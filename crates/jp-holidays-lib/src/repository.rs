@@ -28,8 +28,10 @@ impl HolidayRepository for HolidayRepositoryImpl {
     }
 }
 
+#[cfg(test)]
 pub struct HolidayRepositoryStub;
 
+#[cfg(test)]
 #[async_trait::async_trait]
 impl HolidayRepository for HolidayRepositoryStub {
     async fn fetch_csv(&self) -> Result<bytes::Bytes, crate::error::Error> {
@@ -0,0 +1,603 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 毎年の休業日を決める基準日です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anchor {
+    /// 毎年の固定日 (例: 6月1日)
+    Date { month: u32, day: u32 },
+
+    /// 毎年の期間 (例: 12月29日から1月3日)
+    ///
+    /// 終了日が開始日より前の場合は、翌年の終了日までの期間になります。
+    Span {
+        start_month: u32,
+        start_day: u32,
+        end_month: u32,
+        end_day: u32,
+    },
+
+    /// 毎年の第 N X曜日 (例: 11月の第3金曜日)
+    ///
+    /// `nth` に負の値を指定すると、月末から数えます。`-1` は最終 X曜日です。
+    NthWeekday {
+        month: u32,
+        nth: i32,
+        weekday: chrono::Weekday,
+    },
+
+    /// 毎年の祝日 (例: 成人の日)
+    Holiday(crate::holiday::HolidayId),
+}
+
+/// 基準日に対して順番に適用する操作です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    /// 日数をずらします。負の値の場合は前にずらします。
+    Offset(i64),
+
+    /// ルールを評価するカレンダーの翌営業日に移動します。
+    NextBusinessDay,
+
+    /// ルールを評価するカレンダーの前営業日に移動します。
+    PreviousBusinessDay,
+
+    /// 指定した曜日の場合のみ残し、それ以外の日を取り除きます。
+    OnlyOn(Vec<chrono::Weekday>),
+}
+
+/// 毎年繰り返す会社独自の休業日のルールです。
+///
+/// 基準日 (`Anchor`) を決め、必要に応じて操作 (`Step`) を順番に適用します。
+/// 祝日を基準にしたルールや、営業日への移動を含むルールは、評価に渡したカレンダーの祝日と休日を使います。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::Weekday;
+/// use jp_holidays_lib::{client::Client, holiday::HolidayId, rule::Rule};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     // 毎年 12月29日 から 1月3日
+///     let year_end = Rule::span(12, 29, 1, 3)?.with_label("年末年始");
+///
+///     // 11月の第3金曜日
+///     let sports_festival = Rule::nth_weekday(11, 3, Weekday::Fri)?.with_label("社内運動会");
+///
+///     // 成人の日の翌営業日
+///     let after_coming_of_age = Rule::holiday(HolidayId::ComingOfAgeDay).next_business_day();
+///
+///     // 春分の日の翌日が金曜日の場合
+///     let spring_bridge = Rule::holiday(HolidayId::VernalEquinoxDay)
+///         .offset(1)
+///         .only_on([Weekday::Fri]);
+///
+///     for rule in [year_end, sports_festival, after_coming_of_age, spring_bridge] {
+///         println!("{}: {:?}", rule.label(), rule.dates_in_year(&client, 2026)?);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    anchor: Anchor,
+    steps: Vec<Step>,
+    label: String,
+}
+
+impl Rule {
+    /// 基準日を渡してルールを作成します。
+    pub fn new(anchor: Anchor) -> Self {
+        Self {
+            anchor,
+            steps: Vec::new(),
+            label: "休業日".to_string(),
+        }
+    }
+
+    /// 毎年の固定日のルールを作成します。
+    ///
+    /// 2月29日はうるう年のみ対象になります。
+    /// それ以外の存在しない月日 (13月1日や4月31日など) の場合は `crate::error::Error::InvalidDate` を返します。
+    pub fn date(month: u32, day: u32) -> Result<Self, crate::error::Error> {
+        validate_month_day(month, day)?;
+        Ok(Self::new(Anchor::Date { month, day }))
+    }
+
+    /// 毎年の期間のルールを作成します。終了日が開始日より前の場合は年をまたぎます。
+    ///
+    /// 開始日か終了日が2月29日の場合は、うるう年のみ対象になります。
+    /// それ以外の存在しない月日の場合は `crate::error::Error::InvalidDate` を返します。
+    pub fn span(
+        start_month: u32,
+        start_day: u32,
+        end_month: u32,
+        end_day: u32,
+    ) -> Result<Self, crate::error::Error> {
+        validate_month_day(start_month, start_day)?;
+        validate_month_day(end_month, end_day)?;
+        Ok(Self::new(Anchor::Span {
+            start_month,
+            start_day,
+            end_month,
+            end_day,
+        }))
+    }
+
+    /// 毎年の第 N X曜日のルールを作成します。`nth` が負の場合は月末から数えます。
+    ///
+    /// `month` が 1 から 12 でない場合や、`nth` が 1 から 5 (または -1 から -5) でない場合は
+    /// `crate::error::Error::InvalidDate` を返します。
+    pub fn nth_weekday(
+        month: u32,
+        nth: i32,
+        weekday: chrono::Weekday,
+    ) -> Result<Self, crate::error::Error> {
+        validate_nth_weekday(month, nth, weekday)?;
+        Ok(Self::new(Anchor::NthWeekday {
+            month,
+            nth,
+            weekday,
+        }))
+    }
+
+    /// 毎年の祝日を基準にしたルールを作成します。
+    pub fn holiday(id: crate::holiday::HolidayId) -> Self {
+        Self::new(Anchor::Holiday(id))
+    }
+
+    /// 日数をずらす操作を追加します。
+    pub fn offset(self, days: i64) -> Self {
+        self.then(Step::Offset(days))
+    }
+
+    /// 翌営業日に移動する操作を追加します。
+    pub fn next_business_day(self) -> Self {
+        self.then(Step::NextBusinessDay)
+    }
+
+    /// 前営業日に移動する操作を追加します。
+    pub fn previous_business_day(self) -> Self {
+        self.then(Step::PreviousBusinessDay)
+    }
+
+    /// 指定した曜日の場合のみ残す操作を追加します。
+    pub fn only_on(self, weekdays: impl IntoIterator<Item = chrono::Weekday>) -> Self {
+        self.then(Step::OnlyOn(weekdays.into_iter().collect()))
+    }
+
+    /// 操作を追加します。
+    pub fn then(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// 休業日の名称を設定します。既定値は「休業日」です。
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// 基準日を取得します。
+    pub fn anchor(&self) -> &Anchor {
+        &self.anchor
+    }
+
+    /// 操作の一覧を取得します。
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// 休業日の名称を取得します。
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 指定した年を基準にルールを評価し、休業日を取得します。
+    ///
+    /// 年をまたぐ期間は、開始日の年として扱います。
+    /// 営業日への移動でカレンダーの範囲外に達した場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn dates_in_year<C: crate::business::BusinessCalendar + ?Sized>(
+        &self,
+        calendar: &C,
+        year: i32,
+    ) -> Result<Vec<NaiveDate>, crate::error::Error> {
+        let mut dates = self.anchor_dates(calendar, year)?;
+
+        for step in &self.steps {
+            dates = match step {
+                Step::Offset(days) => dates
+                    .into_iter()
+                    .map(|date| {
+                        date.checked_add_signed(chrono::Duration::days(*days))
+                            .ok_or_else(|| {
+                                crate::error::Error::OutOfRange(format!(
+                                    "{} から {} 日移動できません",
+                                    date, days
+                                ))
+                            })
+                    })
+                    .collect::<Result<_, _>>()?,
+                Step::NextBusinessDay => dates
+                    .into_iter()
+                    .map(|date| calendar.next_business_day(date))
+                    .collect::<Result<_, _>>()?,
+                Step::PreviousBusinessDay => dates
+                    .into_iter()
+                    .map(|date| calendar.previous_business_day(date))
+                    .collect::<Result<_, _>>()?,
+                Step::OnlyOn(weekdays) => dates
+                    .into_iter()
+                    .filter(|date| weekdays.contains(&date.weekday()))
+                    .collect(),
+            };
+        }
+
+        dates.sort();
+        dates.dedup();
+        Ok(dates)
+    }
+
+    /// 期間内のルールによる休業日を取得します。
+    pub fn dates_between<C: crate::business::BusinessCalendar + ?Sized>(
+        &self,
+        calendar: &C,
+        period: std::ops::RangeInclusive<NaiveDate>,
+    ) -> Result<Vec<NaiveDate>, crate::error::Error> {
+        let mut dates = Vec::new();
+
+        // 前年から始まる年またぎの期間も含めるため、1年前から評価します。
+        // 1年前の評価がカレンダーの範囲外に達する場合は、その年を飛ばします。
+        let first_year = period.start().year() - 1;
+        for year in first_year..=period.end().year() {
+            let year_dates = match self.dates_in_year(calendar, year) {
+                Ok(dates) => dates,
+                Err(crate::error::Error::OutOfRange(_)) if year == first_year => continue,
+                Err(error) => return Err(error),
+            };
+            dates.extend(year_dates.into_iter().filter(|date| period.contains(date)));
+        }
+
+        dates.sort();
+        dates.dedup();
+        Ok(dates)
+    }
+
+    fn anchor_dates<C: crate::business::BusinessCalendar + ?Sized>(
+        &self,
+        calendar: &C,
+        year: i32,
+    ) -> Result<Vec<NaiveDate>, crate::error::Error> {
+        let dates = match &self.anchor {
+            Anchor::Date { month, day } => {
+                // 2月29日はうるう年のみ対象にします。
+                validate_month_day(*month, *day)?;
+                NaiveDate::from_ymd_opt(year, *month, *day)
+                    .into_iter()
+                    .collect()
+            }
+            Anchor::Span {
+                start_month,
+                start_day,
+                end_month,
+                end_day,
+            } => {
+                validate_month_day(*start_month, *start_day)?;
+                validate_month_day(*end_month, *end_day)?;
+                let end_year = if (*end_month, *end_day) < (*start_month, *start_day) {
+                    year + 1
+                } else {
+                    year
+                };
+
+                // 2月29日を含む期間は、その日が存在する年のみ対象にします。
+                match (
+                    NaiveDate::from_ymd_opt(year, *start_month, *start_day),
+                    NaiveDate::from_ymd_opt(end_year, *end_month, *end_day),
+                ) {
+                    (Some(start), Some(end)) => {
+                        start.iter_days().take_while(|date| *date <= end).collect()
+                    }
+                    _ => Vec::new(),
+                }
+            }
+            Anchor::NthWeekday {
+                month,
+                nth,
+                weekday,
+            } => nth_weekday_of_month(year, *month, *nth, *weekday)?
+                .into_iter()
+                .collect(),
+            Anchor::Holiday(id) => ymd(year, 1, 1)?
+                .iter_days()
+                .take_while(|date| date.year() == year)
+                .filter(|date| {
+                    calendar
+                        .get_holiday_detail(*date)
+                        .is_some_and(|holiday| holiday.id() == Some(*id))
+                })
+                .collect(),
+        };
+
+        Ok(dates)
+    }
+}
+
+/// 指定した月の第 N X曜日を求めます。`nth` が負の場合は月末から数えます。
+///
+/// その月に該当する日がない場合 (第5月曜日がない月など) は `None` を返します。
+pub(crate) fn nth_weekday_of_month(
+    year: i32,
+    month: u32,
+    nth: i32,
+    weekday: chrono::Weekday,
+) -> Result<Option<NaiveDate>, crate::error::Error> {
+    validate_nth_weekday(month, nth, weekday)?;
+
+    let first = ymd(year, month, 1)?;
+    let candidates: Vec<NaiveDate> = first
+        .iter_days()
+        .take_while(|date| date.month() == month)
+        .filter(|date| date.weekday() == weekday)
+        .collect();
+
+    let index = if nth > 0 {
+        nth as usize - 1
+    } else {
+        match candidates.len().checked_sub(nth.unsigned_abs() as usize) {
+            Some(index) => index,
+            None => return Ok(None),
+        }
+    };
+
+    Ok(candidates.get(index).copied())
+}
+
+/// 月日がうるう年に存在するかどうかを確認します。
+fn validate_month_day(month: u32, day: u32) -> Result<(), crate::error::Error> {
+    NaiveDate::from_ymd_opt(2000, month, day)
+        .map(|_| ())
+        .ok_or(crate::error::Error::InvalidDate(format!(
+            "不正な月日です: {}月 {}日",
+            month, day
+        )))
+}
+
+/// 第 N X曜日の月と `nth` を確認します。
+fn validate_nth_weekday(
+    month: u32,
+    nth: i32,
+    weekday: chrono::Weekday,
+) -> Result<(), crate::error::Error> {
+    if !(1..=12).contains(&month) {
+        return Err(crate::error::Error::InvalidDate(format!(
+            "不正な月です: {}月",
+            month
+        )));
+    }
+    if nth == 0 || !(-5..=5).contains(&nth) {
+        return Err(crate::error::Error::InvalidDate(format!(
+            "第{}{:?}曜日は指定できません",
+            nth, weekday
+        )));
+    }
+    Ok(())
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> Result<NaiveDate, crate::error::Error> {
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(crate::error::Error::InvalidDate(format!(
+        "不正な日付です: {}年 {}月 {}日",
+        year, month, day
+    )))
+}
+
+impl<C: crate::business::BusinessCalendar> crate::overlay::OverlayCalendar<C> {
+    /// ルールによる休業日を、祝日データの範囲のすべての年について追加します。
+    ///
+    /// 祝日や営業日は、このカレンダー (それまでに追加した休業日・出勤日を含みます) で評価します。
+    /// 営業日への移動などでカレンダーの範囲外に達する年は追加しません。
+    ///
+    /// ## 使用例
+    ///
+    /// ```
+    /// use jp_holidays_lib::{client::Client, overlay::OverlayCalendar, rule::Rule};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::init().await?;
+    ///
+    ///     let calendar = OverlayCalendar::new(&client)
+    ///         .with_rule(&Rule::span(12, 29, 1, 3)?.with_label("年末年始"))?;
+    ///
+    ///     for (date, entry) in calendar.list_overrides() {
+    ///         println!("{} | {}", date, entry.label());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_rule(self, rule: &Rule) -> Result<Self, crate::error::Error> {
+        let coverage = crate::business::BusinessCalendar::coverage(&self);
+        let mut calendar = self;
+
+        // 前年から始まる年またぎの期間も含めるため、1年前から評価します。
+        // 営業日への移動などでカレンダーの範囲外に達する年は追加しません。
+        for year in coverage.start().year() - 1..=coverage.end().year() {
+            let dates = match rule.dates_in_year(&calendar, year) {
+                Ok(dates) => dates,
+                Err(crate::error::Error::OutOfRange(_)) => continue,
+                Err(error) => return Err(error),
+            };
+
+            for date in dates.into_iter().filter(|date| coverage.contains(date)) {
+                calendar = calendar.with_closure(date, rule.label());
+            }
+        }
+
+        Ok(calendar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_span_across_year_end() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let dates = Rule::span(12, 29, 1, 3)
+            .unwrap()
+            .dates_in_year(&client, 2025)
+            .unwrap();
+        assert_eq!(dates.first(), Some(&ymd(2025, 12, 29)));
+        assert_eq!(dates.last(), Some(&ymd(2026, 1, 3)));
+        assert_eq!(dates.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_nth_weekday() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let dates = Rule::nth_weekday(11, 3, chrono::Weekday::Fri)
+            .unwrap()
+            .dates_in_year(&client, 2026)
+            .unwrap();
+        assert_eq!(dates, vec![ymd(2026, 11, 20)]);
+
+        let dates = Rule::nth_weekday(11, -1, chrono::Weekday::Mon)
+            .unwrap()
+            .dates_in_year(&client, 2026)
+            .unwrap();
+        assert_eq!(dates, vec![ymd(2026, 11, 30)]);
+    }
+
+    #[tokio::test]
+    async fn test_business_day_after_holiday() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let dates = Rule::holiday(crate::holiday::HolidayId::ComingOfAgeDay)
+            .next_business_day()
+            .dates_in_year(&client, 2026)
+            .unwrap();
+        assert_eq!(dates, vec![ymd(2026, 1, 13)]);
+    }
+
+    #[tokio::test]
+    async fn test_only_on_weekday() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let rule = Rule::holiday(crate::holiday::HolidayId::VernalEquinoxDay)
+            .offset(1)
+            .only_on([chrono::Weekday::Fri]);
+
+        // 2025年の春分の日は木曜日、2026年は金曜日です。
+        assert_eq!(
+            rule.dates_in_year(&client, 2025).unwrap(),
+            vec![ymd(2025, 3, 21)]
+        );
+        assert!(rule.dates_in_year(&client, 2026).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_date_validation() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        assert!(Rule::date(13, 1).is_err());
+        assert!(Rule::date(4, 31).is_err());
+        assert!(
+            Rule::new(Anchor::Date { month: 2, day: 30 })
+                .dates_in_year(&client, 2026)
+                .is_err()
+        );
+
+        // 2月29日はうるう年のみ対象です。
+        let rule = Rule::date(2, 29).unwrap();
+        assert_eq!(
+            rule.dates_in_year(&client, 2024).unwrap(),
+            vec![ymd(2024, 2, 29)]
+        );
+        assert!(rule.dates_in_year(&client, 2026).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_span_and_nth_weekday_validation() {
+        use crate::business::BusinessCalendar;
+
+        assert!(Rule::span(2, 30, 3, 1).is_err());
+        assert!(Rule::span(12, 29, 13, 1).is_err());
+        assert!(Rule::nth_weekday(13, 1, chrono::Weekday::Mon).is_err());
+        assert!(Rule::nth_weekday(11, 0, chrono::Weekday::Mon).is_err());
+        assert!(Rule::nth_weekday(11, 6, chrono::Weekday::Mon).is_err());
+
+        // 2月29日から始まる期間は、うるう年のみ対象です。
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::overlay::OverlayCalendar::new(&client)
+            .with_rule(&Rule::span(2, 29, 3, 1).unwrap())
+            .unwrap();
+        assert!(calendar.is_day_off(ymd(2024, 2, 29)));
+        assert!(calendar.is_day_off(ymd(2024, 3, 1)));
+        assert!(calendar.get_override(ymd(2025, 3, 1)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dates_between_at_coverage_start() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let rule = Rule::date(1, 10).unwrap().previous_business_day();
+
+        // 1954年の評価は祝日データの範囲外に達するため飛ばします。
+        let dates = rule
+            .dates_between(&client, ymd(1955, 1, 1)..=ymd(1955, 12, 31))
+            .unwrap();
+        assert_eq!(dates.len(), 1);
+        assert_eq!(dates[0].year(), 1955);
+    }
+
+    #[tokio::test]
+    async fn test_overlay_with_rule() {
+        use crate::business::BusinessCalendar;
+
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar = crate::overlay::OverlayCalendar::new(&client)
+            .with_rule(&Rule::span(12, 29, 1, 3).unwrap().with_label("年末年始"))
+            .unwrap();
+
+        assert!(calendar.is_day_off(ymd(2025, 12, 30)));
+        assert_eq!(
+            calendar
+                .get_override(ymd(2026, 1, 2))
+                .map(|entry| entry.label()),
+            Some("年末年始")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_overlay_with_rule_uses_extended_calendar() {
+        use crate::business::BusinessCalendar;
+
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let calendar =
+            crate::overlay::OverlayCalendar::new(crate::bank::BankCalendar::new(&client))
+                .with_rule(
+                    &Rule::date(12, 30)
+                        .unwrap()
+                        .next_business_day()
+                        .with_label("仕事始め"),
+                )
+                .unwrap();
+
+        // 銀行の休日 (12月31日〜1月3日) を飛ばして、1月5日 (月) に移動します。
+        assert!(calendar.get_override(ymd(2025, 12, 31)).is_none());
+        assert!(calendar.get_override(ymd(2026, 1, 2)).is_none());
+        assert_eq!(
+            calendar
+                .get_override(ymd(2026, 1, 5))
+                .map(|entry| entry.label()),
+            Some("仕事始め")
+        );
+        assert!(calendar.is_day_off(ymd(2026, 1, 5)));
+    }
+}
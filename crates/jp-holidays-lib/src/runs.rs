@@ -0,0 +1,192 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 名前の付いた大型連休です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedBreak {
+    /// ゴールデンウィーク (5月3日を含む3日以上の連休)
+    GoldenWeek,
+
+    /// シルバーウィーク (敬老の日と秋分の日をともに含む連休)
+    SilverWeek,
+}
+
+/// 休日が連続する期間 (連休) です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayOffRun {
+    /// 連休の初日
+    pub start: NaiveDate,
+
+    /// 連休の最終日
+    pub end: NaiveDate,
+
+    /// 連休に含まれる祝日
+    pub holidays: Vec<crate::holiday::Holiday>,
+}
+
+impl DayOffRun {
+    /// 連休の日数を取得します。
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    /// `chrono::NaiveDate` を渡して、連休に含まれるかどうかを判定します。
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// 連休の名前 (ゴールデンウィーク・シルバーウィーク) を取得します。該当しない場合は `None` を返します。
+    pub fn name(&self) -> Option<NamedBreak> {
+        let has = |id| self.holidays.iter().any(|holiday| holiday.id() == Some(id));
+
+        if has(crate::holiday::HolidayId::RespectForTheAgedDay)
+            && has(crate::holiday::HolidayId::AutumnalEquinoxDay)
+        {
+            return Some(NamedBreak::SilverWeek);
+        }
+
+        let golden_week = (self.start.year()..=self.end.year())
+            .filter_map(|year| NaiveDate::from_ymd_opt(year, 5, 3))
+            .any(|date| self.contains(date));
+        if golden_week && self.days() >= 3 {
+            return Some(NamedBreak::GoldenWeek);
+        }
+
+        None
+    }
+}
+
+/// 期間と重なる連休をすべて取得します。連休は期間の外側まで延ばします。
+pub(crate) fn day_off_runs<C: crate::business::BusinessCalendar + ?Sized>(
+    calendar: &C,
+    period: std::ops::RangeInclusive<NaiveDate>,
+) -> Result<Vec<DayOffRun>, crate::error::Error> {
+    crate::business::ensure_covered(calendar, *period.start())?;
+    crate::business::ensure_covered(calendar, *period.end())?;
+
+    let mut runs = Vec::new();
+    let mut cursor = *period.start();
+
+    while cursor <= *period.end() {
+        if calendar.is_day_off(cursor) {
+            let run = extend(calendar, cursor);
+            cursor = run.end;
+            runs.push(run);
+        }
+
+        cursor = match cursor.succ_opt() {
+            Some(next) => next,
+            None => break,
+        };
+    }
+
+    Ok(runs)
+}
+
+/// `date` を含む連休を取得します。`date` が営業日の場合は `None` を返します。
+pub(crate) fn break_containing<C: crate::business::BusinessCalendar + ?Sized>(
+    calendar: &C,
+    date: NaiveDate,
+) -> Result<Option<DayOffRun>, crate::error::Error> {
+    crate::business::ensure_covered(calendar, date)?;
+
+    if calendar.is_day_off(date) {
+        Ok(Some(extend(calendar, date)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// 休日である `date` から前後に休日が続く限り延ばして、連休を作成します。
+///
+/// 祝日データの範囲の端では、範囲内で止めます。
+fn extend<C: crate::business::BusinessCalendar + ?Sized>(
+    calendar: &C,
+    date: NaiveDate,
+) -> DayOffRun {
+    let coverage = calendar.coverage();
+    let is_day_off = |date: &NaiveDate| coverage.contains(date) && calendar.is_day_off(*date);
+
+    let mut start = date;
+    while let Some(previous) = start.pred_opt().filter(is_day_off) {
+        start = previous;
+    }
+
+    let mut end = date;
+    while let Some(next) = end.succ_opt().filter(is_day_off) {
+        end = next;
+    }
+
+    let holidays = start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter_map(|date| calendar.get_holiday_detail(date).cloned())
+        .collect();
+
+    DayOffRun {
+        start,
+        end,
+        holidays,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_day_off_runs() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let runs = client
+            .day_off_runs(ymd(2026, 4, 27)..=ymd(2026, 5, 10))
+            .unwrap();
+
+        let periods: Vec<(NaiveDate, NaiveDate)> =
+            runs.iter().map(|run| (run.start, run.end)).collect();
+        assert_eq!(
+            periods,
+            vec![
+                (ymd(2026, 4, 29), ymd(2026, 4, 29)),
+                (ymd(2026, 5, 2), ymd(2026, 5, 6)),
+                (ymd(2026, 5, 9), ymd(2026, 5, 10)),
+            ]
+        );
+        assert_eq!(runs[1].days(), 5);
+        assert_eq!(runs[1].holidays.len(), 4);
+        assert_eq!(runs[1].name(), Some(NamedBreak::GoldenWeek));
+        assert_eq!(runs[2].name(), None);
+    }
+
+    #[tokio::test]
+    async fn test_named_breaks() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        let golden_week = client.golden_week(2026).unwrap().unwrap();
+        assert_eq!(
+            (golden_week.start, golden_week.end),
+            (ymd(2026, 5, 2), ymd(2026, 5, 6))
+        );
+
+        // 2026年は9月19日 (土) から23日 (水・秋分の日) まで
+        let silver_week = client.silver_week(2026).unwrap().unwrap();
+        assert_eq!(
+            (silver_week.start, silver_week.end),
+            (ymd(2026, 9, 19), ymd(2026, 9, 23))
+        );
+
+        // 2025年は敬老の日 (9月15日) と秋分の日 (9月23日) が離れています
+        assert_eq!(client.silver_week(2025).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_break_containing() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+
+        let run = client.break_containing(ymd(2026, 5, 4)).unwrap().unwrap();
+        assert_eq!(run.days(), 5);
+        assert_eq!(client.break_containing(ymd(2026, 5, 7)).unwrap(), None);
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::NaiveDate;
+
+/// 支払日などを繰り返す間隔です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    /// 毎月
+    Monthly,
+
+    /// 3か月ごと
+    Quarterly,
+
+    /// 6か月ごと
+    SemiAnnual,
+
+    /// 毎年
+    Annual,
+}
+
+impl Frequency {
+    /// 間隔の月数を取得します。
+    pub fn months(&self) -> u32 {
+        match self {
+            Frequency::Monthly => 1,
+            Frequency::Quarterly => 3,
+            Frequency::SemiAnnual => 6,
+            Frequency::Annual => 12,
+        }
+    }
+}
+
+/// スケジュールの1回分の日付です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScheduledDate {
+    /// 調整前の日付
+    pub unadjusted: NaiveDate,
+
+    /// 営業日調整規則を適用した日付
+    pub adjusted: NaiveDate,
+}
+
+/// 一定の間隔で繰り返す支払日などのスケジュールです。
+///
+/// 開始日から `Frequency` ごとの応当日 (応当日がない場合は月末日) を、終了日まで列挙します。
+/// 開始日は含まず、終了日は応当日であれば含みます。
+/// 各日付には営業日調整規則 (既定は `ModifiedFollowing`) を適用します。
+///
+/// ## 使用例
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use jp_holidays_lib::{
+///     client::Client,
+///     convention::BusinessDayConvention,
+///     schedule::{Frequency, Schedule},
+/// };
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::init().await?;
+///
+///     let start = NaiveDate::from_ymd_opt(2025, 1, 31).ok_or("存在しない日付です".to_string())?;
+///     let end = NaiveDate::from_ymd_opt(2026, 1, 31).ok_or("存在しない日付です".to_string())?;
+///
+///     let schedule = Schedule::new(start, end, Frequency::Quarterly)
+///         .with_convention(BusinessDayConvention::Following);
+///
+///     for date in schedule.generate(&client)? {
+///         println!("{} -> {}", date.unadjusted, date.adjusted);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    start: NaiveDate,
+    end: NaiveDate,
+    frequency: Frequency,
+    convention: crate::convention::BusinessDayConvention,
+}
+
+impl Schedule {
+    /// 開始日・終了日・間隔を渡して初期化します。
+    pub fn new(start: NaiveDate, end: NaiveDate, frequency: Frequency) -> Self {
+        Self {
+            start,
+            end,
+            frequency,
+            convention: crate::convention::BusinessDayConvention::default(),
+        }
+    }
+
+    /// 営業日調整規則を設定します。
+    pub fn with_convention(mut self, convention: crate::convention::BusinessDayConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// 調整前の日付を列挙します。
+    pub fn unadjusted_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        (1..)
+            .map_while(|count: u32| {
+                let months = count.checked_mul(self.frequency.months())?;
+                self.start.checked_add_months(chrono::Months::new(months))
+            })
+            .take_while(|date| *date <= self.end)
+    }
+
+    /// `calendar` の休日に営業日調整規則を適用して、スケジュールを作成します。
+    ///
+    /// 調整に祝日データの範囲外の日付が必要な場合は `crate::error::Error::OutOfRange` を返します。
+    pub fn generate<C: crate::business::BusinessCalendar + ?Sized>(
+        &self,
+        calendar: &C,
+    ) -> Result<Vec<ScheduledDate>, crate::error::Error> {
+        self.unadjusted_dates()
+            .map(|unadjusted| {
+                Ok(ScheduledDate {
+                    unadjusted,
+                    adjusted: calendar.adjust(unadjusted, self.convention)?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_monthly_schedule_end_of_month() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let schedule = Schedule::new(ymd(2026, 1, 31), ymd(2026, 6, 30), Frequency::Monthly);
+        let dates = schedule.generate(&client).unwrap();
+
+        let unadjusted: Vec<NaiveDate> = dates.iter().map(|date| date.unadjusted).collect();
+        assert_eq!(
+            unadjusted,
+            vec![
+                ymd(2026, 2, 28),
+                ymd(2026, 3, 31),
+                ymd(2026, 4, 30),
+                ymd(2026, 5, 31),
+                ymd(2026, 6, 30),
+            ]
+        );
+
+        // 2026年2月28日 (土) と5月31日 (日) は月内の前営業日に調整
+        assert_eq!(dates[0].adjusted, ymd(2026, 2, 27));
+        assert_eq!(dates[3].adjusted, ymd(2026, 5, 29));
+    }
+
+    #[tokio::test]
+    async fn test_quarterly_schedule_following() {
+        let client = crate::client::Client::init_stub().await.unwrap();
+        let dates = Schedule::new(ymd(2025, 11, 3), ymd(2026, 11, 3), Frequency::Quarterly)
+            .with_convention(crate::convention::BusinessDayConvention::Following)
+            .generate(&client)
+            .unwrap();
+
+        let adjusted: Vec<NaiveDate> = dates.iter().map(|date| date.adjusted).collect();
+        // 2026年2月3日 (火)、5月3日 (日・憲法記念日)、8月3日 (月)、11月3日 (火・文化の日)
+        assert_eq!(
+            adjusted,
+            vec![
+                ymd(2026, 2, 3),
+                ymd(2026, 5, 7),
+                ymd(2026, 8, 3),
+                ymd(2026, 11, 4),
+            ]
+        );
+    }
+}